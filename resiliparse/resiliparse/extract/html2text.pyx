@@ -50,6 +50,7 @@ cdef extern from * nogil:
         bool alt_texts = true;
         bool form_fields = false;
         bool noscript = false;
+        bool normalize_spaces = false;
     };
 
     struct ExtractContext {
@@ -85,6 +86,7 @@ cdef extern from * nogil:
         bint alt_texts
         bint form_fields
         bint noscript
+        bint normalize_spaces
 
     cdef struct ExtractContext:
         lxb_dom_node_t * root_node
@@ -129,6 +131,39 @@ cdef string _get_collapsed_string(const string& input_str) noexcept nogil:
 cdef string LIST_BULLET = <const char*>b'\xe2\x80\xa2'
 
 
+cdef string _normalize_spaces(const string& input_str) noexcept nogil:
+    """
+    Replace Unicode space separators (such as non-breaking spaces) with ASCII spaces
+    and remove zero-width characters from a UTF-8 string.
+    """
+    cdef string output
+    output.reserve(input_str.size())
+    cdef size_t i = 0
+    cdef unsigned char c, c1, c2
+    while i < input_str.size():
+        c = input_str[i]
+        c1 = input_str[i + 1] if i + 1 < input_str.size() else 0
+        c2 = input_str[i + 2] if i + 2 < input_str.size() else 0
+
+        if c == 0xc2 and c1 == 0xa0:
+            # U+00A0 NO-BREAK SPACE
+            output.push_back(b' ')
+            i += 2
+        elif (c == 0xe2 and c1 == 0x80 and (0x80 <= c2 <= 0x8a or c2 == 0xaf)) or \
+                (c == 0xe2 and c1 == 0x81 and c2 == 0x9f) or (c == 0xe3 and c1 == 0x80 and c2 == 0x80):
+            # U+2000-U+200A, U+202F, U+205F, U+3000 (Unicode space separators)
+            output.push_back(b' ')
+            i += 3
+        elif (c == 0xe2 and c1 == 0x80 and 0x8b <= c2 <= 0x8d) or (c == 0xe2 and c1 == 0x81 and c2 == 0xa0) or \
+                (c == 0xef and c1 == 0xbb and c2 == 0xbf):
+            # U+200B-U+200D, U+2060, U+FEFF (zero-width characters)
+            i += 3
+        else:
+            output.push_back(<char>c)
+            i += 1
+    return output
+
+
 cdef inline void _ensure_text_contents(vector[shared_ptr[ExtractNode]]& extract_nodes) noexcept nogil:
     if not deref(extract_nodes.back()).text_contents:
         deref(extract_nodes.back()).text_contents = make_shared[string]()
@@ -193,6 +228,8 @@ cdef void _extract_cb(vector[shared_ptr[ExtractNode]]& extract_nodes, ExtractCon
         _ensure_text_contents(extract_nodes)
         node_char_data = <lxb_dom_character_data_t*>ctx.node
         element_text = string(<const char*>node_char_data.data.data, node_char_data.data.length)
+        if ctx.opts.normalize_spaces:
+            element_text = _normalize_spaces(element_text)
 
         if deref(current_node).tag_id == LXB_TAG_A and ctx.opts.preserve_formatting >= FormattingOpts.FORMAT_MINIMAL_HTML:
             # Escape <a> inner text
@@ -233,7 +270,10 @@ cdef void _extract_cb(vector[shared_ptr[ExtractNode]]& extract_nodes, ExtractCon
         _ensure_text_contents(extract_nodes)
         element_text_sv = get_node_attr_sv(ctx.node, b'alt')
         if not element_text_sv.empty():
-            deref(deref(current_node).text_contents).append(<string>element_text_sv)
+            element_text = <string>element_text_sv
+            if ctx.opts.normalize_spaces:
+                element_text = _normalize_spaces(element_text)
+            deref(deref(current_node).text_contents).append(element_text)
 
     elif ctx.opts.form_fields and ctx.node.local_name in [LXB_TAG_TEXTAREA, LXB_TAG_BUTTON]:
         if not is_end_tag:
@@ -253,7 +293,10 @@ cdef void _extract_cb(vector[shared_ptr[ExtractNode]]& extract_nodes, ExtractCon
             if not element_text_sv.empty():
                 _ensure_text_contents(extract_nodes)
                 element_text.append(b'[ ')
-                element_text.append(<string> element_text_sv)
+                if ctx.opts.normalize_spaces:
+                    element_text.append(_normalize_spaces(<string>element_text_sv))
+                else:
+                    element_text.append(<string> element_text_sv)
                 element_text.append(b' ] ')
                 _ensure_text_contents(extract_nodes)
                 deref(deref(current_node).text_contents).append(element_text)
@@ -837,7 +880,8 @@ cdef string _extract_simplified_dom_impl(HTMLTree tree,
         links,
         alt_texts,
         form_fields,
-        noscript]
+        noscript,
+        False]
 
     if ctx.node.type == LXB_DOM_NODE_TYPE_DOCUMENT:
         ctx.root_node = next_element_node(ctx.node, ctx.node.first_child)
@@ -945,10 +989,12 @@ def extract_plain_text(html,
                        bint form_fields=False,
                        bint noscript=False,
                        bint comments=True,
-                       skip_elements=None):
+                       skip_elements=None,
+                       bint normalize_spaces=False):
     """
     extract_plain_text(html, preserve_formatting=True, main_content=False, list_bullets=True, alt_texts=False, \
-                       links=True, form_fields=False, noscript=False, comments=None, skip_elements=None)
+                       links=True, form_fields=False, noscript=False, comments=None, skip_elements=None, \
+                       normalize_spaces=False)
 
     Perform a simple plain-text extraction from the given DOM node and its children.
 
@@ -964,6 +1010,11 @@ def extract_plain_text(html,
     can be configured individually by setting the corresponding parameter to ``True``.
     Defaults to ``False`` for most elements (i.e., only basic text will be extracted).
 
+    HTML entities such as ``&amp;`` or ``&#x2019;`` are always decoded. Non-breaking spaces (``&nbsp;``)
+    and other Unicode space characters are kept as they are, unless ``normalize_spaces`` is ``True``, in which
+    case they are treated like regular spaces and zero-width characters (such as ``U+200B`` or ``U+FEFF``)
+    are removed.
+
     :param html: HTML as DOM tree or Unicode string
     :type html: HTMLTree or str
     :param preserve_formatting: preserve basic block-level formatting (use ``'minimal_html'`` for minimal HTML
@@ -985,6 +1036,8 @@ def extract_plain_text(html,
     :param skip_elements: list of CSS selectors for elements to skip
     :type skip_elements: t.Iterable[str] or None
     :type noscript: bool
+    :param normalize_spaces: replace Unicode spaces with ASCII spaces and remove zero-width characters
+    :type normalize_spaces: bool
     :return: extracted plain text
     :rtype: str
     """
@@ -1029,7 +1082,8 @@ def extract_plain_text(html,
             form_fields,
             noscript,
             comments,
-            skip_selector)
+            skip_selector,
+            normalize_spaces)
     return extracted.decode(errors='ignore')
 
 cdef string _extract_plain_text_impl(HTMLTree tree,
@@ -1041,7 +1095,8 @@ cdef string _extract_plain_text_impl(HTMLTree tree,
                                      bint form_fields,
                                      bint noscript,
                                      bint comments,
-                                     string skip_selector) noexcept nogil:
+                                     string skip_selector,
+                                     bint normalize_spaces) noexcept nogil:
     """Internal extractor implementation not requiring GIL."""
 
     cdef ExtractContext ctx
@@ -1053,7 +1108,8 @@ cdef string _extract_plain_text_impl(HTMLTree tree,
         links,
         alt_texts,
         form_fields,
-        noscript]
+        noscript,
+        normalize_spaces]
 
     cdef const lxb_char_t* tag_name = NULL
     cdef size_t tag_name_len
@@ -0,0 +1,233 @@
+# Copyright 2023 Janek Bevendorff
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+# distutils: language = c++
+
+import typing as t
+
+from resiliparse.parse.html cimport *
+from resiliparse_inc.lexbor cimport *
+
+
+__all__ = [
+    'SanitizePolicy',
+    'sanitize_html',
+]
+
+
+DEFAULT_ALLOWED_TAGS = frozenset([
+    'a', 'abbr', 'b', 'blockquote', 'br', 'caption', 'cite', 'code', 'col', 'colgroup', 'dd', 'del', 'dfn', 'div',
+    'dl', 'dt', 'em', 'figcaption', 'figure', 'h1', 'h2', 'h3', 'h4', 'h5', 'h6', 'hr', 'i', 'img', 'ins', 'kbd',
+    'li', 'mark', 'ol', 'p', 'pre', 'q', 's', 'samp', 'small', 'span', 'strong', 'sub', 'sup', 'table', 'tbody',
+    'td', 'tfoot', 'th', 'thead', 'time', 'tr', 'u', 'ul'])
+
+DEFAULT_ALLOWED_ATTRS = {
+    '*': frozenset(['dir', 'lang', 'title']),
+    'a': frozenset(['href', 'rel']),
+    'blockquote': frozenset(['cite']),
+    'img': frozenset(['alt', 'height', 'src', 'width']),
+    'ol': frozenset(['start']),
+    'q': frozenset(['cite']),
+    'td': frozenset(['colspan', 'rowspan']),
+    'th': frozenset(['colspan', 'rowspan', 'scope']),
+    'time': frozenset(['datetime']),
+}
+
+DEFAULT_DROP_TAGS = frozenset([
+    'applet', 'embed', 'frame', 'frameset', 'head', 'iframe', 'math', 'noscript', 'object', 'script', 'select',
+    'style', 'svg', 'template', 'textarea'])
+
+DEFAULT_URL_SCHEMES = frozenset(['http', 'https', 'mailto'])
+
+# Never unwrap these, since their contents are code and not text
+cdef frozenset ALWAYS_DROP_TAGS = frozenset(['script', 'style'])
+
+cdef frozenset URL_ATTRS = frozenset(['action', 'background', 'cite', 'formaction', 'href', 'longdesc', 'poster',
+                                      'src', 'srcset', 'xlink:href'])
+
+
+class SanitizePolicy:
+    """
+    __init__(self, allowed_tags=None, allowed_attrs=None, drop_tags=None, url_schemes=None)
+
+    HTML sanitization policy for :func:`sanitize_html`.
+
+    Elements whose tag is not in ``allowed_tags`` are unwrapped, i.e., the element itself is removed,
+    but its children are kept. Elements in ``drop_tags`` are removed together with all their contents.
+    ``<script>`` and ``<style>`` elements are always removed with their contents, even if a custom
+    ``drop_tags`` set does not list them.
+    ``allowed_attrs`` maps tag names to sets of allowed attribute names. Attributes listed under the
+    special key ``'*'`` are allowed on all elements. Event handler attributes (``on*``) are always removed.
+
+    URL-valued attributes (such as ``href`` or ``src``) are removed if their value has a URL scheme that
+    is not in ``url_schemes``. Relative URLs are always allowed. This removes ``javascript:``, ``vbscript:``,
+    and ``data:`` URLs unless explicitly allowed.
+
+    Any parameter set to ``None`` uses a default value suitable for keeping basic formatting markup
+    (headings, paragraphs, lists, tables, links, images, inline text formatting).
+
+    :param allowed_tags: allowed element tag names
+    :type allowed_tags: t.Iterable[str] or None
+    :param allowed_attrs: allowed attributes per tag name
+    :type allowed_attrs: t.Dict[str, t.Iterable[str]] or None
+    :param drop_tags: element tag names to remove together with their contents
+    :type drop_tags: t.Iterable[str] or None
+    :param url_schemes: allowed URL schemes in URL-valued attributes
+    :type url_schemes: t.Iterable[str] or None
+    """
+
+    def __init__(self, allowed_tags=None, allowed_attrs=None, drop_tags=None, url_schemes=None):
+        self.allowed_tags = frozenset(tag.lower() for tag in allowed_tags) \
+            if allowed_tags is not None else DEFAULT_ALLOWED_TAGS
+        self.allowed_attrs = {k.lower(): frozenset(a.lower() for a in v) for k, v in allowed_attrs.items()} \
+            if allowed_attrs is not None else dict(DEFAULT_ALLOWED_ATTRS)
+        self.drop_tags = (frozenset(tag.lower() for tag in drop_tags)
+                          if drop_tags is not None else DEFAULT_DROP_TAGS) | ALWAYS_DROP_TAGS
+        self.url_schemes = frozenset(s.lower() for s in url_schemes) \
+            if url_schemes is not None else DEFAULT_URL_SCHEMES
+
+
+cdef inline lxb_dom_node_t* _next_skip_children(const lxb_dom_node_t* root_node, lxb_dom_node_t* node) noexcept nogil:
+    """Next pre-order node after ``node`` without descending into its children."""
+    while node != root_node and not node.next:
+        node = node.parent
+    if node == root_node:
+        return NULL
+    return node.next
+
+
+cdef inline str _node_name(lxb_dom_node_t* node):
+    cdef size_t name_len = 0
+    cdef const lxb_char_t* name = lxb_dom_node_name(node, &name_len)
+    if not name:
+        return ''
+    return name[:name_len].decode().lower()
+
+
+cdef inline bint _url_allowed(str url, frozenset url_schemes):
+    """Check whether a URL is relative or has an allowed scheme."""
+    # Browsers ignore leading white space and control characters as well as embedded tabs and newlines
+    url = ''.join(c for c in url if ord(c) > 0x20 or c == ' ').strip().lower()
+    cdef Py_ssize_t i
+    for i in range(len(url)):
+        if url[i] == ':':
+            return url[:i] in url_schemes
+        if url[i] in '/?#':
+            break
+    return True
+
+
+cdef void _sanitize_attrs(lxb_dom_node_t* node, str tag, policy):
+    cdef frozenset allowed = policy.allowed_attrs.get(tag, frozenset())
+    cdef frozenset allowed_global = policy.allowed_attrs.get('*', frozenset())
+    cdef lxb_dom_attr_t* attr = lxb_dom_element_first_attribute(<lxb_dom_element_t*>node)
+    cdef lxb_dom_attr_t* next_attr
+    cdef const lxb_char_t* attr_data
+    cdef size_t attr_len = 0
+    cdef str attr_name
+    cdef bint keep
+
+    while attr:
+        next_attr = attr.next
+        attr_data = lxb_dom_attr_local_name(attr, &attr_len)
+        attr_name = attr_data[:attr_len].decode().lower()
+        keep = (attr_name in allowed or attr_name in allowed_global) and not attr_name.startswith('on')
+        if keep and attr_name in URL_ATTRS:
+            attr_data = lxb_dom_attr_value(attr, &attr_len)
+            keep = not attr_data or _url_allowed(attr_data[:attr_len].decode(errors='ignore'), policy.url_schemes)
+        if not keep:
+            lxb_dom_element_attr_remove(<lxb_dom_element_t*>node, attr)
+        attr = next_attr
+
+
+cdef void _sanitize_tree(lxb_dom_node_t* root_node, policy):
+    cdef lxb_dom_node_t* node = root_node.first_child
+    cdef lxb_dom_node_t* next_n
+    cdef lxb_dom_node_t* child
+    cdef str tag
+
+    while node:
+        if node.type == LXB_DOM_NODE_TYPE_TEXT:
+            node = next_node(root_node, node)
+            continue
+
+        if node.type != LXB_DOM_NODE_TYPE_ELEMENT:
+            # Comments, processing instructions etc.
+            next_n = _next_skip_children(root_node, node)
+            lxb_dom_node_remove(node)
+            node = next_n
+            continue
+
+        tag = _node_name(node)
+        if tag in policy.drop_tags:
+            next_n = _next_skip_children(root_node, node)
+            lxb_dom_node_remove(node)
+            node = next_n
+            continue
+
+        if tag not in policy.allowed_tags:
+            # Unwrap element and continue with its first child (if any)
+            next_n = node.first_child
+            if not next_n:
+                next_n = _next_skip_children(root_node, node)
+            child = node.first_child
+            while child:
+                lxb_dom_node_remove(child)
+                lxb_dom_node_insert_before(node, child)
+                child = node.first_child
+            lxb_dom_node_remove(node)
+            node = next_n
+            continue
+
+        _sanitize_attrs(node, tag, policy)
+        node = next_node(root_node, node)
+
+
+def sanitize_html(html, policy=None):
+    """
+    sanitize_html(html, policy=None)
+
+    Sanitize an HTML document and serialize the cleaned body contents back to HTML.
+
+    Unlike :func:`~.extract.html2text.extract_plain_text`, this function keeps formatting markup,
+    but removes all elements and attributes not allowed by the given :class:`SanitizePolicy`.
+    By default, scripts, styles, frames, embedded objects, event handler attributes, and
+    ``javascript:`` URLs are removed, while basic formatting markup is kept. Comments are always removed.
+
+    If ``html`` is an :class:`~.parse.html.HTMLTree`, the tree will be modified in place.
+
+    :param html: HTML as DOM tree or Unicode string
+    :type html: HTMLTree or str
+    :param policy: sanitization policy (``None`` for the default policy)
+    :type policy: SanitizePolicy or None
+    :return: sanitized HTML contents of the document body
+    :rtype: str
+    """
+    cdef HTMLTree tree
+    if isinstance(html, str):
+        tree = HTMLTree.parse(html)
+    elif isinstance(html, HTMLTree):
+        tree = <HTMLTree>html
+    else:
+        raise TypeError('Parameter "html" is neither string nor HTMLTree.')
+
+    if policy is None:
+        policy = SanitizePolicy()
+
+    cdef DOMNode body = tree.body
+    if not check_node(body):
+        return ''
+
+    _sanitize_tree(body.node, policy)
+    return body.inner_html
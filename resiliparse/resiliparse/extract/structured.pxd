@@ -0,0 +1,25 @@
+# Copyright 2023 Janek Bevendorff
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+# distutils: language = c++
+
+
+cpdef enum LinkType:
+    URL = 0x01,
+    FRAGMENT = 0x02,
+    JAVASCRIPT = 0x03,
+    MAILTO = 0x04,
+    TEL = 0x05,
+    DATA = 0x06,
+    OTHER = 0x07
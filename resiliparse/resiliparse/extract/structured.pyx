@@ -0,0 +1,979 @@
+# Copyright 2023 Janek Bevendorff
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+# distutils: language = c++
+
+import base64
+import binascii
+import json
+import typing as t
+from urllib.parse import unquote_to_bytes, urljoin
+
+from resiliparse.parse.html cimport *
+
+
+__all__ = [
+    'DataUri',
+    'Form',
+    'FormField',
+    'HeadDirectives',
+    'Link',
+    'StructuredData',
+    'Table',
+    'TextNode',
+    'extract_forms',
+    'extract_head_directives',
+    'extract_links',
+    'extract_structured_data',
+    'extract_tables',
+    'iter_text_nodes',
+    'parse_data_uri',
+
+    'LinkType',
+    'URL',
+    'FRAGMENT',
+    'JAVASCRIPT',
+    'MAILTO',
+    'TEL',
+    'DATA',
+    'OTHER',
+]
+
+
+# Elements and attributes that reference other resources
+cdef tuple LINK_ATTRS = (
+    ('a', 'href'),
+    ('area', 'href'),
+    ('link', 'href'),
+    ('img', 'src'),
+    ('script', 'src'),
+    ('iframe', 'src'),
+    ('frame', 'src'),
+    ('embed', 'src'),
+    ('source', 'src'),
+    ('track', 'src'),
+    ('audio', 'src'),
+    ('video', 'src'),
+)
+
+cdef str LINK_SELECTOR = ','.join([f'{tag}[{attr}]' for tag, attr in LINK_ATTRS])
+cdef dict LINK_ATTR_MAP = dict(LINK_ATTRS)
+
+
+cdef inline HTMLTree _get_tree(html):
+    if isinstance(html, str):
+        return HTMLTree.parse(html)
+    elif isinstance(html, HTMLTree):
+        return <HTMLTree>html
+    raise TypeError('Parameter "html" is neither string nor HTMLTree.')
+
+
+cdef inline str _clean_url(str url):
+    """Strip surrounding white space and remove embedded tabs and newlines as browsers do."""
+    return url.strip(' \t\n\r\f').replace('\t', '').replace('\n', '').replace('\r', '')
+
+
+cdef str _get_base_url(HTMLTree tree, str base_url):
+    """Determine the document base URL from a ``<base href>`` element and the given base URL."""
+    cdef DOMNode base = tree.document.query_selector('base[href]') if tree.document is not None else None
+    if base is None:
+        return base_url
+    cdef str href = _clean_url(base.getattr('href'))
+    if not href:
+        return base_url
+    return urljoin(base_url, href) if base_url else href
+
+
+cdef LinkType _classify_url(str url):
+    if not url or url.startswith('#'):
+        return FRAGMENT
+
+    cdef str url_lower = url.lower()
+    cdef Py_ssize_t i
+    cdef str scheme = ''
+    for i in range(len(url_lower)):
+        if url_lower[i] == ':':
+            scheme = url_lower[:i]
+            break
+        if url_lower[i] in '/?#':
+            break
+
+    if not scheme or scheme in ('http', 'https'):
+        return URL
+    if scheme == 'javascript':
+        return JAVASCRIPT
+    if scheme == 'mailto':
+        return MAILTO
+    if scheme == 'tel':
+        return TEL
+    if scheme == 'data':
+        return DATA
+    return OTHER
+
+
+class Link:
+    """
+    A link extracted from an HTML document.
+
+    :ivar str url: the link URL, resolved against the document base URL if it is relative
+    :ivar str raw_url: the link URL as it appears in the document
+    :ivar LinkType link_type: link type classification
+    :ivar str tag: tag name of the element the link was found in
+    :ivar str attr: name of the attribute the link was found in
+    :ivar str text: collapsed text contents of the element (for anchor links)
+    :ivar str rel: value of the element's ``rel`` attribute (empty if unset)
+    """
+
+    __slots__ = ('url', 'raw_url', 'link_type', 'tag', 'attr', 'text', 'rel')
+
+    def __init__(self, url, raw_url, link_type, tag, attr, text='', rel=''):
+        self.url = url
+        self.raw_url = raw_url
+        self.link_type = link_type
+        self.tag = tag
+        self.attr = attr
+        self.text = text
+        self.rel = rel
+
+    def __eq__(self, other):
+        if not isinstance(other, Link):
+            return NotImplemented
+        return all(getattr(self, a) == getattr(other, a) for a in self.__slots__)
+
+    def __repr__(self):
+        return f'<Link {self.tag}@{self.attr} "{self.url}">'
+
+
+def extract_links(html, str base_url=None):
+    """
+    extract_links(html, base_url=None)
+
+    Extract all links from an HTML document in document order.
+
+    Links are collected from the URL attributes of anchors and other elements that reference
+    resources (``a@href``, ``area@href``, ``link@href``, ``img@src``, ``script@src``, ``iframe@src``,
+    ``frame@src``, ``embed@src``, and ``src`` attributes of media elements).
+
+    Relative URLs are resolved against the document's ``<base href>`` (if any), which itself is resolved
+    against ``base_url``. If neither exists, relative URLs are returned unchanged. Fragment-only,
+    ``javascript:``, ``mailto:``, ``tel:``, and ``data:`` links are not dropped, but classified by their
+    :class:`LinkType`. Only :data:`URL` and :data:`FRAGMENT` links are resolved.
+
+    :param html: HTML as DOM tree or Unicode string
+    :type html: HTMLTree or str
+    :param base_url: URL of the document for resolving relative links
+    :type base_url: str or None
+    :return: list of extracted links
+    :rtype: t.List[Link]
+    """
+    cdef HTMLTree tree = _get_tree(html)
+    if tree.document is None:
+        return []
+
+    cdef str doc_base_url = _get_base_url(tree, base_url)
+    cdef list links = []
+    cdef DOMNode element
+    cdef str tag, attr, raw_url, url, text
+    cdef LinkType link_type
+
+    for element in tree.document.query_selector_all(LINK_SELECTOR):
+        tag = element.tag
+        attr = LINK_ATTR_MAP[tag]
+        raw_url = element.getattr(attr)
+        url = _clean_url(raw_url)
+        link_type = _classify_url(url)
+        if doc_base_url and link_type in (URL, FRAGMENT):
+            url = urljoin(doc_base_url, url)
+        text = ' '.join(element.text.split()) if tag == 'a' else ''
+        links.append(Link(url, raw_url, link_type, tag, attr, text, element.getattr('rel', '')))
+
+    return links
+
+
+class HeadDirectives:
+    """
+    Indexing directives and metadata extracted from an HTML document's ``<head>``.
+
+    :ivar t.Set[str] robots: lower-cased robots directives (e.g., ``{'noindex', 'nofollow'}``)
+    :ivar str or None canonical: canonical URL of the document (``None`` if unset)
+    :ivar t.Dict[str, str] opengraph: OpenGraph ``og:*`` properties
+    :ivar t.Dict[str, str] twitter: Twitter card ``twitter:*`` properties
+    """
+
+    __slots__ = ('robots', 'canonical', 'opengraph', 'twitter')
+
+    def __init__(self, robots=None, canonical=None, opengraph=None, twitter=None):
+        self.robots = robots if robots is not None else set()
+        self.canonical = canonical
+        self.opengraph = opengraph if opengraph is not None else {}
+        self.twitter = twitter if twitter is not None else {}
+
+    @property
+    def noindex(self):
+        """
+        Whether the document must not be indexed.
+
+        :type: bool
+        """
+        return 'noindex' in self.robots or 'none' in self.robots
+
+    @property
+    def nofollow(self):
+        """
+        Whether links on the document must not be followed.
+
+        :type: bool
+        """
+        return 'nofollow' in self.robots or 'none' in self.robots
+
+    def __repr__(self):
+        return f'<HeadDirectives robots={sorted(self.robots)} canonical={self.canonical!r}>'
+
+
+def extract_head_directives(html, str base_url=None, str user_agent=None):
+    """
+    extract_head_directives(html, base_url=None, user_agent=None)
+
+    Extract robots directives, the canonical URL, and OpenGraph and Twitter card metadata
+    from the ``<head>`` of an HTML document.
+
+    Robots directives are collected from all ``<meta name="robots">`` elements. If ``user_agent``
+    is given (e.g., ``googlebot``), directives from ``<meta>`` elements with that name are included as well.
+    The canonical URL is taken from the first ``<link rel="canonical">`` element and resolved
+    against the document base URL (see :func:`extract_links`). For repeated OpenGraph or Twitter card
+    properties, only the first value is kept.
+
+    :param html: HTML as DOM tree or Unicode string
+    :type html: HTMLTree or str
+    :param base_url: URL of the document for resolving a relative canonical URL
+    :type base_url: str or None
+    :param user_agent: name of a crawler user agent whose specific robots directives should be included
+    :type user_agent: str or None
+    :return: extracted head directives
+    :rtype: HeadDirectives
+    """
+    cdef HTMLTree tree = _get_tree(html)
+    directives = HeadDirectives()
+    cdef DOMNode head = tree.head
+    if head is None:
+        return directives
+
+    cdef set robots_names = {'robots'}
+    if user_agent:
+        robots_names.add(user_agent.lower())
+
+    cdef DOMNode element
+    cdef str name, content
+    for element in head.query_selector_all('meta[content]'):
+        content = element.getattr('content').strip()
+        name = element.getattr('name', '').strip().lower()
+        if name in robots_names:
+            directives.robots.update(d.strip().lower() for d in content.split(',') if d.strip())
+            continue
+
+        if not name:
+            name = element.getattr('property', '').strip().lower()
+        if name.startswith('og:'):
+            directives.opengraph.setdefault(name, content)
+        elif name.startswith('twitter:'):
+            directives.twitter.setdefault(name, content)
+
+    cdef str doc_base_url
+    for element in head.query_selector_all('link[rel][href]'):
+        if 'canonical' not in element.getattr('rel').lower().split():
+            continue
+        directives.canonical = _clean_url(element.getattr('href'))
+        doc_base_url = _get_base_url(tree, base_url)
+        if doc_base_url:
+            directives.canonical = urljoin(doc_base_url, directives.canonical)
+        break
+
+    return directives
+
+
+class Table:
+    """
+    A table extracted from an HTML document.
+
+    Cells spanning multiple rows or columns are repeated in each grid position they cover,
+    so all rows have the same number of cells.
+
+    :ivar str caption: collapsed text of the table caption (empty if unset)
+    :ivar t.List[str] headers: header cell texts (empty if the table has no header row)
+    :ivar t.List[t.List[str]] rows: cell texts of all non-header rows
+    """
+
+    __slots__ = ('caption', 'headers', 'rows')
+
+    def __init__(self, caption='', headers=None, rows=None):
+        self.caption = caption
+        self.headers = headers if headers is not None else []
+        self.rows = rows if rows is not None else []
+
+    def records(self):
+        """
+        records(self)
+
+        Return the table rows as dicts keyed by their header cell texts. Columns without
+        header text and columns whose header text was already used by a previous column
+        (e.g., because of a ``colspan``) are keyed by their column index.
+
+        :return: list of row records
+        :rtype: t.List[t.Dict[str or int, str]]
+        """
+        cdef list keys = []
+        cdef set seen = set()
+        for i, h in enumerate(self.headers):
+            keys.append(i if not h or h in seen else h)
+            seen.add(h)
+        keys.extend(range(len(keys), max((len(r) for r in self.rows), default=0)))
+        return [dict(zip(keys, row)) for row in self.rows]
+
+    def __repr__(self):
+        return f'<Table {len(self.rows)}x{len(self.headers) or (len(self.rows[0]) if self.rows else 0)}>'
+
+
+cdef str _text_without_tables(DOMNode node):
+    """Collapsed text contents of ``node`` excluding nested tables."""
+    cdef list parts = []
+    cdef list stack = list(reversed(node.child_nodes))
+    cdef DOMNode n
+    while stack:
+        n = stack.pop()
+        if n.type == TEXT:
+            parts.append(n.text)
+        elif n.type == ELEMENT and n.tag != 'table':
+            if n.tag == 'br':
+                parts.append(' ')
+            stack.extend(reversed(n.child_nodes))
+    return ' '.join(''.join(parts).split())
+
+
+cdef inline int _span_value(DOMNode cell, str attr, int max_value, bint zero_is_max=False):
+    cdef str value = cell.getattr(attr, '1').strip()
+    cdef int span
+    try:
+        span = max(min(int(value), max_value), -1)
+    except ValueError:
+        return 1
+    if span == 0 and zero_is_max:
+        # rowspan="0" spans all remaining rows of the row group
+        return max_value
+    return max(span, 1)
+
+
+cdef object _extract_table(DOMNode table, size_t* max_cells, bint* truncated):
+    cdef list grid = []
+    cdef list header_flags = []
+    cdef dict carried = {}      # column -> [remaining rows, text] for cells spanning into the next rows
+    cdef dict spans
+    cdef DOMNode row, cell, parent, row_group = None
+    cdef list grid_row
+    cdef bint all_th
+    cdef int col, colspan, rowspan, i
+    cdef size_t width = 0
+    cdef str text
+
+    for row in table.query_selector_all('tr'):
+        if row.closest('table') is not table:
+            continue
+
+        # Cells cannot span beyond the end of their row group (thead, tbody, or tfoot)
+        parent = row.parent
+        if parent is not row_group:
+            carried = {}
+            row_group = parent
+
+        grid_row = []
+        spans = {}
+        all_th = True
+        col = 0
+        for cell in row.child_nodes:
+            if cell.type != ELEMENT or cell.tag not in ('td', 'th'):
+                continue
+            all_th = all_th and cell.tag == 'th'
+
+            while col in carried:
+                grid_row.append(carried[col][1])
+                col += 1
+
+            text = _text_without_tables(cell)
+            colspan = _span_value(cell, 'colspan', 1000)
+            rowspan = _span_value(cell, 'rowspan', 65534, True)
+
+            # Spans can inflate small inputs into huge grids, so stop at the cell limit
+            if (len(grid) + 1) * max(width, <size_t>(col + colspan)) > max_cells[0]:
+                truncated[0] = True
+                break
+
+            for i in range(colspan):
+                grid_row.append(text)
+                if rowspan > 1:
+                    spans[col] = [rowspan - 1, text]
+                col += 1
+
+        if truncated[0]:
+            break
+
+        # Fill trailing columns still covered by cells from previous rows
+        while carried and col <= max(carried):
+            grid_row.append(carried[col][1] if col in carried else '')
+            col += 1
+
+        if (len(grid) + 1) * max(width, <size_t>len(grid_row)) > max_cells[0]:
+            truncated[0] = True
+            break
+        width = max(width, <size_t>len(grid_row))
+
+        carried = {k: [v[0] - 1, v[1]] for k, v in carried.items() if v[0] > 1}
+        carried.update(spans)
+
+        header_flags.append(bool(grid_row) and (all_th or (parent is not None and parent.tag == 'thead')))
+        grid.append(grid_row)
+
+    max_cells[0] -= len(grid) * width
+
+    cdef DOMNode caption = table.query_selector('caption')
+    cdef object result = Table(_text_without_tables(caption) if caption is not None and
+                               caption.closest('table') is table else '')
+    if not grid:
+        return result
+
+    for grid_row in grid:
+        grid_row.extend([''] * (width - len(grid_row)))
+
+    if header_flags[0]:
+        result.headers = grid[0]
+        grid = grid[1:]
+    result.rows = [r for r in grid if any(r)]
+    return result
+
+
+def extract_tables(html, size_t max_cells=1000000):
+    """
+    extract_tables(html, max_cells=1000000)
+
+    Extract all tables from an HTML document as rows of cell texts.
+
+    The first row of a table is used as header row if it is part of a ``<thead>`` or consists
+    only of ``<th>`` cells. Cells with ``colspan`` or ``rowspan`` attributes are expanded, so that
+    each cell's text appears in all grid positions it covers. Like in web browsers, cells do not span
+    beyond the end of their row group (``<thead>``, ``<tbody>``, or ``<tfoot>``) and ``rowspan="0"``
+    spans all remaining rows of the row group. Nested tables are returned as separate
+    :class:`Table` objects (in document order after their parent table) and their text is excluded
+    from the cells containing them. Empty rows are skipped.
+
+    Since row and column spans can expand a few bytes of HTML into a very large grid, the total number
+    of cells across all extracted tables is limited to ``max_cells``. Once the limit is reached, the
+    remaining rows of the current table and all following tables are skipped.
+
+    :param html: HTML as DOM tree or Unicode string
+    :type html: HTMLTree or str
+    :param max_cells: maximum total number of expanded table cells (0 for no limit)
+    :type max_cells: int
+    :return: list of extracted tables
+    :rtype: t.List[Table]
+    """
+    cdef HTMLTree tree = _get_tree(html)
+    if tree.body is None:
+        return []
+
+    cdef size_t cells_left = max_cells if max_cells else <size_t>-1
+    cdef bint truncated = False
+    cdef list tables = []
+    cdef DOMNode table
+    for table in tree.body.query_selector_all('table'):
+        tables.append(_extract_table(table, &cells_left, &truncated))
+        if truncated:
+            break
+    return tables
+
+
+class FormField:
+    """
+    An input field of an HTML form.
+
+    :ivar str name: field name (empty if unset)
+    :ivar str type: field type, i.e., the lower-cased ``type`` attribute of ``<input>`` and ``<button>`` elements
+                    or ``textarea`` and ``select`` for the respective elements
+    :ivar str value: default value of the field
+    """
+
+    __slots__ = ('name', 'type', 'value')
+
+    def __init__(self, name, type, value=''):
+        self.name = name
+        self.type = type
+        self.value = value
+
+    def __eq__(self, other):
+        if not isinstance(other, FormField):
+            return NotImplemented
+        return all(getattr(self, a) == getattr(other, a) for a in self.__slots__)
+
+    def __repr__(self):
+        return f'<FormField {self.type} "{self.name}">'
+
+
+class Form:
+    """
+    A form extracted from an HTML document.
+
+    :ivar str action: form action URL, resolved against the document base URL if it is relative
+    :ivar str raw_action: form action URL as it appears in the document (empty if unset)
+    :ivar str method: lower-cased form method (``get``, ``post``, or ``dialog``)
+    :ivar t.List[FormField] fields: input fields of the form in document order
+    """
+
+    __slots__ = ('action', 'raw_action', 'method', 'fields')
+
+    def __init__(self, action, raw_action, method='get', fields=None):
+        self.action = action
+        self.raw_action = raw_action
+        self.method = method
+        self.fields = fields if fields is not None else []
+
+    def __repr__(self):
+        return f'<Form {self.method.upper()} "{self.action}">'
+
+
+cdef frozenset FORM_METHODS = frozenset(['get', 'post', 'dialog'])
+
+
+cdef object _extract_form_field(DOMNode element):
+    cdef str tag = element.tag
+    cdef str name = element.getattr('name', '')
+    cdef str field_type
+    cdef DOMNode option, selected = None
+
+    if tag == 'textarea':
+        return FormField(name, 'textarea', element.text)
+
+    if tag == 'select':
+        for option in element.query_selector_all('option'):
+            if selected is None or option.hasattr('selected'):
+                selected = option
+            if option.hasattr('selected'):
+                break
+        if selected is None:
+            return FormField(name, 'select')
+        if selected.hasattr('value'):
+            return FormField(name, 'select', selected.getattr('value'))
+        return FormField(name, 'select', ' '.join(selected.text.split()))
+
+    field_type = element.getattr('type', '').strip().lower()
+    if not field_type:
+        field_type = 'submit' if tag == 'button' else 'text'
+    return FormField(name, field_type, element.getattr('value', ''))
+
+
+def extract_forms(html, str base_url=None):
+    """
+    extract_forms(html, base_url=None)
+
+    Extract all forms from an HTML document together with their input fields.
+
+    Fields are collected from ``<input>``, ``<textarea>``, ``<select>``, and ``<button>`` elements inside
+    a form or associated with it via a ``form`` attribute. The default value of a ``<select>`` field is the
+    value of its first selected option (or its first option if none is selected).
+
+    Relative action URLs are resolved against the document base URL (see :func:`extract_links`).
+    A missing or empty action resolves to ``base_url`` itself. Missing or invalid form methods default
+    to ``get``.
+
+    :param html: HTML as DOM tree or Unicode string
+    :type html: HTMLTree or str
+    :param base_url: URL of the document for resolving relative action URLs
+    :type base_url: str or None
+    :return: list of extracted forms
+    :rtype: t.List[Form]
+    """
+    cdef HTMLTree tree = _get_tree(html)
+    if tree.document is None:
+        return []
+
+    cdef str doc_base_url = _get_base_url(tree, base_url)
+    cdef dict forms = {}
+    cdef DOMNode element, owner
+    cdef str raw_action, action, method, form_id
+
+    for element in tree.document.query_selector_all('form'):
+        raw_action = element.getattr('action', '')
+        action = _clean_url(raw_action)
+        if not action:
+            action = base_url or ''
+        elif doc_base_url and _classify_url(action) == URL:
+            action = urljoin(doc_base_url, action)
+        method = element.getattr('method', '').strip().lower()
+        forms[element] = Form(action, raw_action, method if method in FORM_METHODS else 'get')
+
+    if not forms:
+        return []
+
+    for element in tree.document.query_selector_all('input, textarea, select, button'):
+        if element.hasattr('form'):
+            # Explicit form owner, which need not be an ancestor
+            form_id = element.getattr('form')
+            owner = tree.document.get_element_by_id(form_id) if form_id else None
+        else:
+            owner = element.closest('form')
+        if owner is not None and owner in forms:
+            forms[owner].fields.append(_extract_form_field(element))
+
+    return list(forms.values())
+
+
+class TextNode:
+    """
+    A DOM text node together with information about its element context.
+
+    :ivar str text: text contents of the node
+    :ivar DOMNode node: the text :class:`~.parse.html.DOMNode` itself
+    :ivar DOMNode parent: parent element of the text node
+    :ivar str tag: tag name of the parent element
+    :ivar bool is_block: whether the parent element is a block-level element
+    :ivar bool hidden: whether the text is inside an element that is hidden via the ``hidden``
+                       or ``aria-hidden`` attributes or an inline ``display: none`` or ``visibility: hidden`` style
+    :ivar bool in_script: whether the text is inside a ``<script>``, ``<style>``, or ``<noscript>`` element
+    """
+
+    __slots__ = ('text', 'node', 'parent', 'tag', 'is_block', 'hidden', 'in_script')
+
+    def __init__(self, text, node, parent, tag, is_block, hidden, in_script):
+        self.text = text
+        self.node = node
+        self.parent = parent
+        self.tag = tag
+        self.is_block = is_block
+        self.hidden = hidden
+        self.in_script = in_script
+
+    def __repr__(self):
+        return f'<TextNode {self.tag} "{self.text}">'
+
+
+cdef frozenset SCRIPT_TAGS = frozenset(['script', 'style', 'noscript'])
+
+
+cdef bint _is_hidden_element(DOMNode element):
+    if element.hasattr('hidden') or element.getattr('aria-hidden', '').strip().lower() == 'true':
+        return True
+    cdef str style = element.getattr('style')
+    if not style:
+        return False
+    style = ''.join(style.lower().split())
+    return 'display:none' in style or 'visibility:hidden' in style
+
+
+def iter_text_nodes(html, bint skip_whitespace=True):
+    """
+    iter_text_nodes(html, skip_whitespace=True)
+
+    Iterate all text nodes of an HTML document in document order together with their element context.
+
+    This is a lower-level primitive than :func:`~.extract.html2text.extract_plain_text` for
+    implementing custom extraction logic. Unlike the plain-text extractor, it does not skip any
+    content, but flags text in hidden elements or scripts, so you can filter it yourself.
+
+    The tree must not be modified while iterating.
+
+    :param html: HTML as DOM tree or Unicode string
+    :type html: HTMLTree or str
+    :param skip_whitespace: skip text nodes that contain only white space
+    :type skip_whitespace: bool
+    :return: generator of text nodes
+    :rtype: t.Iterator[TextNode]
+    """
+    cdef HTMLTree tree = _get_tree(html)
+    cdef DOMNode root = tree.document
+    if root is None:
+        return
+
+    cdef list stack = []
+    cdef bint hidden = False
+    cdef bint in_script = False
+    cdef DOMNode node = root.first_child
+    cdef DOMNode parent
+    cdef str text
+
+    while node is not None:
+        if node.type == TEXT:
+            text = node.text
+            if not skip_whitespace or not text.isspace():
+                parent = node.parent
+                yield TextNode(text, node, parent, parent.tag,
+                               parent.type == ELEMENT and is_block_element(parent.node.local_name),
+                               hidden, in_script)
+
+        elif node.type == ELEMENT and node.first_child is not None:
+            stack.append((hidden, in_script))
+            hidden = hidden or _is_hidden_element(node)
+            in_script = in_script or node.tag in SCRIPT_TAGS
+            node = node.first_child
+            continue
+
+        while node is not root and node.next is None:
+            node = node.parent
+            if node.type == ELEMENT:
+                hidden, in_script = stack.pop()
+        node = node.next if node is not root else None
+
+
+class StructuredData:
+    """
+    A JSON-LD block or top-level microdata item extracted from an HTML document.
+
+    :ivar str syntax: ``'json-ld'`` or ``'microdata'``
+    :ivar data: parsed data (``None`` if the JSON-LD block is malformed)
+    :ivar str or None error: error message if the data could not be parsed
+    :ivar str or None raw: raw JSON-LD source text (``None`` for microdata)
+    """
+
+    __slots__ = ('syntax', 'data', 'error', 'raw')
+
+    def __init__(self, syntax, data=None, error=None, raw=None):
+        self.syntax = syntax
+        self.data = data
+        self.error = error
+        self.raw = raw
+
+    @property
+    def valid(self):
+        """
+        Whether the data could be parsed without errors.
+
+        :type: bool
+        """
+        return self.error is None
+
+    def __repr__(self):
+        if self.error is not None:
+            return f'<StructuredData {self.syntax} error="{self.error}">'
+        return f'<StructuredData {self.syntax}>'
+
+
+cdef frozenset MICRODATA_SRC_TAGS = frozenset(['audio', 'embed', 'iframe', 'img', 'source', 'track', 'video'])
+cdef frozenset MICRODATA_HREF_TAGS = frozenset(['a', 'area', 'link'])
+
+
+cdef inline str _resolve(str url, str base_url):
+    url = _clean_url(url)
+    return urljoin(base_url, url) if base_url else url
+
+
+cdef object _parse_json_ld(str text):
+    cdef str json_text = text.strip()
+    # Some pages wrap JSON-LD in HTML comments or CDATA sections
+    if json_text.startswith('<!--') and json_text.endswith('-->'):
+        json_text = json_text[4:-3].strip()
+    if json_text.startswith('//<![CDATA[') and json_text.endswith('//]]>'):
+        json_text = json_text[11:-5].strip()
+    try:
+        return StructuredData('json-ld', json.loads(json_text), raw=text)
+    except (ValueError, RecursionError) as e:
+        # Deeply nested JSON exceeds the interpreter's recursion limit
+        return StructuredData('json-ld', error=str(e), raw=text)
+
+
+cdef object _microdata_value(DOMNode element, str base_url):
+    cdef str tag = element.tag
+    if tag == 'meta':
+        return element.getattr('content', '')
+    if tag in MICRODATA_SRC_TAGS:
+        return _resolve(element.getattr('src', ''), base_url)
+    if tag in MICRODATA_HREF_TAGS:
+        return _resolve(element.getattr('href', ''), base_url)
+    if tag == 'object':
+        return _resolve(element.getattr('data', ''), base_url)
+    if tag in ('data', 'meter'):
+        return element.getattr('value', '')
+    if tag == 'time' and element.hasattr('datetime'):
+        return element.getattr('datetime')
+    return ' '.join(element.text.split())
+
+
+cdef dict _microdata_push_item(DOMNode item, str base_url, HTMLTree tree, list frames, set path):
+    """Create an empty item dict and push its property elements as a new frame onto ``frames``."""
+    cdef dict result = {}
+    cdef list item_types = item.getattr('itemtype', '').split()
+    if item_types:
+        result['type'] = item_types
+    if item.hasattr('itemid'):
+        result['id'] = _resolve(item.getattr('itemid'), base_url)
+
+    cdef dict properties = {}
+    result['properties'] = properties
+
+    cdef list stack = []
+    cdef DOMNode ref
+    cdef str ref_id
+
+    # Referenced elements are processed after the item's own children
+    for ref_id in reversed(item.getattr('itemref', '').split()):
+        ref = tree.document.get_element_by_id(ref_id)
+        if ref is not None:
+            stack.append(ref)
+    stack.extend(reversed(item.child_nodes))
+
+    path.add(item)
+    frames.append((item, properties, stack))
+    return result
+
+
+cdef dict _microdata_item(DOMNode item, str base_url, HTMLTree tree):
+    # Nested items are filled from an explicit stack of frames, so deep nesting does not exhaust the native stack
+    cdef list frames = []
+    cdef set path = set()       # Items currently being filled, for detecting cyclic itemrefs
+    cdef dict result = _microdata_push_item(item, base_url, tree, frames, path)
+    cdef dict properties
+    cdef list stack, names
+    cdef DOMNode element
+    cdef str name
+
+    while frames:
+        item, properties, stack = frames[-1]
+        if not stack:
+            frames.pop()
+            path.discard(item)
+            continue
+
+        element = stack.pop()
+        if element.type != ELEMENT:
+            continue
+
+        names = element.getattr('itemprop', '').split()
+        if element.hasattr('itemscope'):
+            if names:
+                # Cyclic itemrefs are represented as None
+                value = _microdata_push_item(element, base_url, tree, frames, path) if element not in path else None
+                for name in names:
+                    properties.setdefault(name, []).append(value)
+            continue
+
+        if names:
+            value = _microdata_value(element, base_url)
+            for name in names:
+                properties.setdefault(name, []).append(value)
+        stack.extend(reversed(element.child_nodes))
+
+    return result
+
+
+def extract_structured_data(html, str base_url=None):
+    """
+    extract_structured_data(html, base_url=None)
+
+    Extract schema.org and other structured data from JSON-LD blocks and microdata items in an HTML document.
+
+    JSON-LD blocks (``<script type="application/ld+json">``) are parsed as JSON. Malformed blocks are not dropped,
+    but returned with an :attr:`StructuredData.error` message and their raw source text.
+
+    Top-level microdata items (elements with an ``itemscope`` but without an ``itemprop`` attribute) are
+    converted into dicts following the JSON serialization of the HTML microdata specification, i.e.,
+    with a ``type`` list (if the item has an ``itemtype``), an ``id`` (if the item has an ``itemid``),
+    and a ``properties`` dict mapping property names to lists of values. Nested items are converted
+    as well and ``itemref`` attributes are followed. URL property values are resolved against the
+    document base URL (see :func:`extract_links`).
+
+    Results are returned in document order.
+
+    :param html: HTML as DOM tree or Unicode string
+    :type html: HTMLTree or str
+    :param base_url: URL of the document for resolving relative URLs in microdata properties
+    :type base_url: str or None
+    :return: list of extracted structured data
+    :rtype: t.List[StructuredData]
+    """
+    cdef HTMLTree tree = _get_tree(html)
+    if tree.document is None:
+        return []
+
+    cdef str doc_base_url = _get_base_url(tree, base_url)
+    cdef list results = []
+    cdef DOMNode element
+    for element in tree.document.query_selector_all('script[type], [itemscope]'):
+        if element.tag == 'script':
+            if element.getattr('type').split(';')[0].strip().lower() == 'application/ld+json':
+                results.append(_parse_json_ld(element.text))
+        elif not element.hasattr('itemprop'):
+            results.append(StructuredData('microdata', _microdata_item(element, doc_base_url, tree)))
+
+    return results
+
+
+class DataUri:
+    """
+    Decoded contents of a ``data:`` URI.
+
+    :ivar str mime_type: media type of the data including parameters (``text/plain;charset=US-ASCII`` if unset)
+    :ivar bool is_base64: whether the data was Base64-encoded
+    :ivar bytes data: decoded data
+    """
+
+    __slots__ = ('mime_type', 'is_base64', 'data')
+
+    def __init__(self, mime_type, is_base64, data):
+        self.mime_type = mime_type
+        self.is_base64 = is_base64
+        self.data = data
+
+    def __repr__(self):
+        return f'<DataUri {self.mime_type} ({len(self.data)} bytes)>'
+
+
+def parse_data_uri(str uri):
+    """
+    parse_data_uri(uri)
+
+    Parse and decode a ``data:`` URI.
+
+    Percent-encoded data is decoded first, then Base64-encoded data (marked by ``;base64``) is decoded.
+    Like in web browsers, white space in Base64 data is ignored and missing padding is tolerated.
+    Use this together with :func:`extract_links` to extract inline assets from a document.
+
+    :param uri: data URI
+    :type uri: str
+    :return: decoded data URI or ``None`` if ``uri`` is not a valid data URI
+    :rtype: DataUri or None
+    """
+    uri = uri.strip(' \t\n\r\f')
+    if uri[:5].lower() != 'data:':
+        return None
+
+    cdef Py_ssize_t comma = uri.find(',', 5)
+    if comma == -1:
+        return None
+
+    cdef str mime_type = uri[5:comma].strip(' \t\n\r\f')
+    cdef bytes data = unquote_to_bytes(uri[comma + 1:])
+    cdef bint is_base64 = False
+    cdef Py_ssize_t semicolon = mime_type.rfind(';')
+    if semicolon != -1 and mime_type[semicolon + 1:].strip(' ').lower() == 'base64':
+        is_base64 = True
+        mime_type = mime_type[:semicolon].strip(' ')
+        data = b''.join(data.split())
+        if len(data) % 4 == 1:
+            return None
+        data += b'=' * (-len(data) % 4)
+        try:
+            data = base64.b64decode(data, validate=True)
+        except binascii.Error:
+            return None
+
+    if mime_type.startswith(';'):
+        mime_type = 'text/plain' + mime_type
+    if not mime_type:
+        mime_type = 'text/plain;charset=US-ASCII'
+
+    return DataUri(mime_type, is_base64, data)
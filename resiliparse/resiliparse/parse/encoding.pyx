@@ -33,6 +33,7 @@ __all__ = [
     'detect_encoding',
     'detect_mime',
     'map_encoding_to_html5',
+    'strip_bom',
 ]
 
 # Encoding name and label map according to https://encoding.spec.whatwg.org/#names-and-labels
@@ -193,6 +194,25 @@ def __chardet_exit():
     __chardet = None
 
 
+# The UTF-32-LE BOM starts with the UTF-16-LE BOM, so it must be checked first
+cdef tuple __boms = (
+    (codecs.BOM_UTF8, 'utf-8'),
+    (codecs.BOM_UTF32_LE, 'utf-32-le'),
+    (codecs.BOM_UTF32_BE, 'utf-32-be'),
+    (codecs.BOM_UTF16_LE, 'utf-16-le'),
+    (codecs.BOM_UTF16_BE, 'utf-16-be'),
+)
+
+
+cdef inline tuple __find_bom(bytes data):
+    cdef bytes bom
+    cdef str enc
+    for bom, enc in __boms:
+        if data.startswith(bom):
+            return bom, enc
+    return b'', None
+
+
 cpdef str detect_encoding(bytes data, size_t max_len=131072, bint html5_compatible=True, bint from_html_meta=False):
     """
     detect_encoding(data, max_len=131072, html5_compatible=True, from_html_meta=False)
@@ -221,6 +241,10 @@ cpdef str detect_encoding(bytes data, size_t max_len=131072, bint html5_compatib
     If WHATWG remapping is enabled, UTF-8 is returned as a fallback encoding. Otherwise, the method returns
     ``None`` on failure to detect the encoding.
 
+    If WHATWG remapping is enabled and the string starts with a UTF-8 or UTF-16 byte order mark, the encoding
+    implied by the BOM is returned right away and takes precedence over both the HTML meta charset and `uchardet`
+    (see :func:`strip_bom`). UTF-32 is not a WHATWG encoding, so UTF-32 BOMs are left to `uchardet`.
+
     :param data: input string for which to detect the encoding
     :type data: bytes
     :param max_len: maximum number of bytes to feed to detector (0 for no limit)
@@ -236,6 +260,13 @@ cpdef str detect_encoding(bytes data, size_t max_len=131072, bint html5_compatib
     cdef lxb_html_encoding_t html_enc
     cdef lxb_html_encoding_entry_t* html_enc_entry
     cdef size_t meta_peek_size = min(len(data), 1024)
+    cdef str bom_enc
+
+    if html5_compatible:
+        bom_enc = __find_bom(data)[1]
+        if bom_enc is not None and not bom_enc.startswith('utf-32'):
+            return bom_enc
+
     if from_html_meta:
         if lxb_html_encoding_init(&html_enc) == LXB_STATUS_OK and lxb_html_encoding_determine(
                 &html_enc, <lxb_char_t*>data, <lxb_char_t*>data + meta_peek_size) == LXB_STATUS_OK:
@@ -302,6 +333,33 @@ cpdef str map_encoding_to_html5(str encoding, bint fallback_utf8=True):
     return __enc_html5_map.get(encoding.strip().casefold(), 'utf-8' if fallback_utf8 else None)
 
 
+cpdef tuple strip_bom(bytes data):
+    """
+    strip_bom(data)
+
+    Strip a byte order mark (BOM) from the beginning of a byte string.
+
+    Returns a tuple of the byte string without the BOM and the name of the encoding implied by the BOM
+    (``utf-8``, ``utf-16-le``, ``utf-16-be``, ``utf-32-le``, or ``utf-32-be``). If the string does not
+    start with a BOM, it is returned unchanged together with ``None``.
+
+    A BOM is a stronger signal than any declared or detected charset, so the returned encoding
+    should override those. :func:`detect_encoding` already does this for UTF-8 and UTF-16 BOMs if
+    ``html5_compatible=True``, but leaves UTF-32 BOMs to `uchardet`, since UTF-32 is not a WHATWG encoding.
+
+    :param data: input byte string
+    :type data: bytes
+    :return: tuple of byte string without BOM and BOM encoding (or ``None``)
+    :rtype: t.Tuple[bytes, str or None]
+    """
+    cdef bytes bom
+    cdef str enc
+    bom, enc = __find_bom(data)
+    if enc is None:
+        return data, None
+    return data[len(bom):], enc
+
+
 cdef inline str __map_utf(str enc, bytes data, bint strip):
     if not strip:
         return enc
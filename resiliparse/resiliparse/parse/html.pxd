@@ -64,6 +64,8 @@ cdef lxb_dom_collection_t* query_selector_all_impl(lxb_dom_node_t* node, HTMLTre
                                                    const char* selector, size_t selector_len,
                                                    size_t init_size=*) except <lxb_dom_collection_t*>-1 nogil
 cdef bint matches_impl(lxb_dom_node_t* node, HTMLTree tree, const char* selector, size_t selector_len) noexcept nogil
+cdef lxb_dom_node_t* closest_impl(lxb_dom_node_t* node, HTMLTree tree,
+                                  const char* selector, size_t selector_len) except <lxb_dom_node_t*>-1 nogil
 
 cdef extern from "html.h" nogil:
     cdef lxb_tag_id_t BLOCK_ELEMENTS[]
@@ -102,11 +104,13 @@ cdef class DOMNode:
     cpdef DOMNode query_selector(self, str selector)
     cpdef DOMCollection query_selector_all(self, str selector)
     cpdef bint matches(self, str selector) except -1
+    cpdef DOMNode closest(self, str selector)
 
     cpdef DOMNode append_child(self, DOMNode node)
     cpdef DOMNode insert_before(self, DOMNode node, DOMNode reference)
     cpdef DOMNode replace_child(self, DOMNode new_child, DOMNode old_child)
     cpdef DOMNode remove_child(self, DOMNode node)
+    cpdef DOMNode remove(self)
     cpdef decompose(self)
 
 
@@ -391,6 +391,38 @@ cdef bint matches_impl(lxb_dom_node_t* node, HTMLTree tree, const char* selector
     return matches
 
 
+cdef lxb_dom_node_t* closest_impl(lxb_dom_node_t* node, HTMLTree tree,
+                                  const char* selector, size_t selector_len) except <lxb_dom_node_t*>-1 nogil:
+    """
+    Return the closest inclusive ancestor element matching the given CSS selector.
+
+    :param node: anchor node
+    :param tree: owning HTML tree
+    :param selector: CSS selector as bytes
+    :param selector_len: CSS selector length in bytes
+    :return: pointer to matching element or ``NULL`` if no ancestor matches
+    """
+    tree.init_css_parser()
+
+    cdef lxb_selectors_t* selectors = lxb_selectors_create()
+    lxb_selectors_init(selectors)
+    cdef lxb_css_selector_list_t* sel_list = parse_css_selectors(tree.css_parser,
+                                                                 <const lxb_char_t*>selector, selector_len)
+    cdef bint matches = False
+    while node and sel_list:
+        if node.type == LXB_DOM_NODE_TYPE_ELEMENT:
+            lxb_selectors_match_node(selectors, node, sel_list, <lxb_selectors_cb_f>css_match_callback,
+                                     <void*>&matches)
+            if matches:
+                break
+        node = node.parent
+
+    destroy_css_selectors(tree.css_parser)
+    lxb_selectors_destroy(selectors, True)
+
+    return node if matches else NULL
+
+
 cdef inline bint is_whitespace(const char c):
     return c == b' ' or c == b'\t' or c == b'\n' or c == b'\f' or c == b'\r'
 
@@ -792,6 +824,28 @@ cdef class DOMNode:
         cdef bytes html_bytes = html.encode()
         lxb_html_element_inner_html_set(<lxb_html_element_t*>self.node, <const lxb_char_t*>html_bytes, len(html_bytes))
 
+    @property
+    def inner_html(self):
+        """
+        HTML contents of this DOM node's children without the node itself.
+
+        Assigning to this property is equivalent to assigning to :attr:`html`.
+
+        :type: str
+        """
+        if not check_node(self):
+            return None
+
+        cdef lexbor_str_t* html_str = lexbor_str_create()
+        lxb_html_serialize_deep_str(self.node, html_str)
+        cdef str py_text = html_str.data[:html_str.length].decode()
+        lexbor_str_destroy(html_str, self.node.owner_document.text, True)
+        return py_text
+
+    @inner_html.setter
+    def inner_html(self, str html):
+        self.html = html
+
     @property
     def id(self):
         """
@@ -1120,6 +1174,29 @@ cdef class DOMNode:
         cdef bytes selector_bytes = selector.encode()
         return matches_impl(self.node, self.tree, <const char*>selector_bytes, len(selector_bytes))
 
+    cpdef DOMNode closest(self, str selector):
+        """
+        closest(self, selector)
+        
+        Find and return the closest ancestor element (starting with this element itself) matching
+        the given CSS selector. Unlike :meth:`query_selector`, which searches the subtree below this
+        node, this method searches towards the document root.
+
+        :param selector: CSS selector
+        :type selector: str
+        :return: closest matching element or ``None`` if no ancestor matches
+        :rtype: DOMNode or None
+        """
+        if not check_node(self):
+            return None
+
+        cdef bytes selector_bytes = selector.encode()
+        cdef lxb_dom_node_t* node = closest_impl(self.node, self.tree, <const char*>selector_bytes,
+                                                 len(selector_bytes))
+        if node == NULL:
+            return None
+        return _create_dom_node(self.tree, node)
+
     cpdef DOMCollection get_elements_by_attr(self, str attr_name, str attr_value, bint case_insensitive=False):
         """
         get_elements_by_attr(self, attr_name, attr_value, case_insensitive=False)
@@ -1315,6 +1392,23 @@ cdef class DOMNode:
         lxb_dom_node_remove(node.node)
         return node
 
+    cpdef DOMNode remove(self):
+        """
+        remove(self)
+        
+        Remove this node from its parent and return it. The node and its subtree remain valid
+        and can be re-inserted into the tree. Does nothing if the node has no parent.
+        
+        :return: the removed node
+        :rtype: DOMNode
+        """
+        if not check_node(self):
+            raise RuntimeError('Remove operation on uninitialized node')
+
+        if self.node.parent:
+            lxb_dom_node_remove(self.node)
+        return self
+
     # noinspection PyAttributeOutsideInit
     cpdef decompose(self):
         """
@@ -0,0 +1,326 @@
+# Copyright 2023 Janek Bevendorff
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+# distutils: language = c++
+
+import re
+import typing as t
+
+from libc.stdint cimport uint64_t
+from libcpp.vector cimport vector
+
+__all__ = [
+    'minhash',
+    'sentence_spans',
+    'simhash64',
+    'split_sentences',
+]
+
+
+# Abbreviations that are usually followed by a period, but do not end a sentence.
+# Multi-part abbreviations are stored without their final period (e.g., "e.g" for "e.g.").
+cdef frozenset __abbrevs_common = frozenset([
+    'approx', 'ca', 'cf', 'co', 'corp', 'dept', 'dr', 'e.g', 'eds', 'est', 'etc', 'fig', 'figs', 'i.e', 'inc',
+    'jr', 'ltd', 'p', 'pp', 'prof', 'sr', 'st', 'vol', 'vols', 'vs'])
+
+# Abbreviations that are ordinary words unless followed by a number ("No. 5", but "The answer is no.")
+cdef frozenset __abbrevs_numeric = frozenset(['no', 'nos'])
+
+cdef dict __abbrevs_lang = {
+    'en': frozenset(['a.m', 'apr', 'aug', 'dec', 'feb', 'gen', 'gov', 'jan', 'jul', 'jun', 'lt', 'mar', 'messrs',
+                     'mr', 'mrs', 'ms', 'mt', 'nov', 'oct', 'p.m', 'rep', 'rev', 'sen', 'sep', 'sept', 'u.s']),
+    'de': frozenset(['abs', 'bzw', 'd.h', 'evtl', 'ggf', 'hr', 'hrn', 'inkl', 'nr', 'o.a', 's', 'sog', 'str',
+                     'u.a', 'u.u', 'usw', 'vgl', 'z.b', 'z.t', 'zzgl']),
+    'fr': frozenset(['av', 'bd', 'env', 'm', 'mlle', 'mm', 'mme', 'p.ex', 'réf']),
+    'es': frozenset(['avda', 'dña', 'd', 'dra', 'ee.uu', 'p.ej', 'sra', 'sras', 'sres', 'srta', 'ud', 'uds']),
+}
+
+cdef frozenset __terminators = frozenset(['.', '!', '?', '…', '。', '！', '？', '｡'])
+cdef frozenset __cjk_terminators = frozenset(['。', '！', '？', '｡'])
+cdef frozenset __closing_punct = frozenset(['"', "'", ')', ']', '}', '»', '”', '’', '」', '』', '）'])
+
+
+cdef inline bint _is_abbrev(str text, Py_ssize_t dot_pos, frozenset abbrevs, bint lang_en):
+    """Check whether the period at ``dot_pos`` terminates a known abbreviation or an initial."""
+    cdef Py_ssize_t start = dot_pos
+    while start > 0 and (text[start - 1].isalpha() or text[start - 1] == '.'):
+        start -= 1
+    cdef str word = text[start:dot_pos]
+    if not word:
+        return False
+
+    # Single-letter initials ("J. R. R. Tolkien"), except for the English pronoun "I"
+    if len(word) == 1 and word.isupper():
+        return not (lang_en and word == 'I')
+
+    word = word.lower()
+    if word in __abbrevs_numeric:
+        dot_pos += 1
+        while dot_pos < len(text) and text[dot_pos].isspace():
+            dot_pos += 1
+        return dot_pos < len(text) and text[dot_pos].isdigit()
+    return word in __abbrevs_common or word in abbrevs
+
+
+cdef inline Py_ssize_t _skip_spaces(str text, Py_ssize_t pos, Py_ssize_t text_len, Py_ssize_t* newlines):
+    newlines[0] = 0
+    while pos < text_len and text[pos].isspace():
+        if text[pos] == '\n':
+            newlines[0] += 1
+        pos += 1
+    return pos
+
+
+def sentence_spans(str text, str lang=None):
+    """
+    sentence_spans(text, lang=None)
+
+    Find sentence boundaries in ``text`` and return them as a list of ``(start, end)`` character
+    offsets. This is the backend of :func:`split_sentences` and is useful if you need to map
+    sentences back to their position in the original text.
+
+    See :func:`split_sentences` for details about the splitting rules.
+
+    :param text: input text
+    :type text: str
+    :param lang: optional ISO 639-1 language code for language-specific abbreviation rules
+    :type lang: str or None
+    :return: list of sentence spans
+    :rtype: t.List[t.Tuple[int, int]]
+    """
+    lang = lang.lower() if lang else 'en'
+    cdef frozenset abbrevs = __abbrevs_lang.get(lang, frozenset())
+    cdef bint lang_en = lang == 'en'
+    cdef list spans = []
+    cdef Py_ssize_t text_len = len(text)
+    cdef Py_ssize_t start = 0
+    cdef Py_ssize_t pos = 0
+    cdef Py_ssize_t end
+    cdef Py_ssize_t next_start
+    cdef Py_ssize_t newlines = 0
+    cdef bint boundary
+    cdef str c
+
+    while pos < text_len:
+        c = text[pos]
+
+        # Paragraph breaks always end a sentence
+        if c == '\n':
+            next_start = _skip_spaces(text, pos, text_len, &newlines)
+            if newlines >= 2:
+                spans.append((start, pos))
+                start = next_start
+            pos = next_start
+            continue
+
+        if c not in __terminators:
+            pos += 1
+            continue
+
+        # Decimal numbers and other periods within a token (3.14, example.com)
+        if c == '.' and 0 < pos < text_len - 1 and not text[pos + 1].isspace() \
+                and text[pos + 1] not in __terminators and text[pos + 1] not in __closing_punct:
+            pos += 1
+            continue
+
+        end = pos + 1
+        boundary = True
+        if c == '.' and _is_abbrev(text, pos, abbrevs, lang_en):
+            boundary = False
+
+        # Consume runs of terminators (ellipses, "?!") and trailing quotes or brackets
+        while end < text_len and text[end] in __terminators:
+            end += 1
+        while end < text_len and text[end] in __closing_punct:
+            end += 1
+
+        next_start = _skip_spaces(text, end, text_len, &newlines)
+        if newlines >= 2:
+            boundary = True
+        elif next_start == end and next_start < text_len and c not in __cjk_terminators:
+            # Latin-script sentences must be followed by white space
+            boundary = False
+        elif next_start < text_len and text[next_start].islower():
+            # Lower-case continuation after ellipsis or abbreviation
+            boundary = False
+
+        if boundary:
+            spans.append((start, end))
+            start = next_start
+        pos = next_start if boundary else end
+
+    spans.append((start, text_len))
+
+    cdef list stripped = []
+    cdef Py_ssize_t s, e
+    for s, e in spans:
+        while s < e and text[s].isspace():
+            s += 1
+        while e > s and text[e - 1].isspace():
+            e -= 1
+        if s < e:
+            stripped.append((s, e))
+    return stripped
+
+
+def split_sentences(str text, str lang=None):
+    """
+    split_sentences(text, lang=None)
+
+    Split a plain text into sentences.
+
+    The splitter is rule-based and intended for fast bulk processing of extracted web page texts.
+    Sentences end at ``.``, ``!``, ``?``, ``…`` (and their CJK full-width equivalents) followed
+    by white space, as well as at paragraph breaks (two or more consecutive newlines). Decimal
+    numbers, ellipses followed by lower-case continuations, single-letter initials, and common
+    abbreviations such as "e.g." or "Dr." do not end a sentence. "No." and "Nos." are treated as
+    abbreviations only if followed by a number, and in English, "I." is not treated as an initial.
+
+    If ``lang`` is given, additional language-specific abbreviations are recognized. Supported
+    languages are ``en`` (the default), ``de``, ``fr``, and ``es``. For any other language,
+    only a small set of language-agnostic abbreviations is used.
+
+    Leading and trailing white space is stripped from each sentence and empty sentences are skipped.
+
+    :param text: input text
+    :type text: str
+    :param lang: optional ISO 639-1 language code for language-specific abbreviation rules
+    :type lang: str or None
+    :return: list of sentences
+    :rtype: t.List[str]
+    """
+    return [text[s:e] for s, e in sentence_spans(text, lang)]
+
+
+cdef object __word_regex = re.compile(r'\w+', re.UNICODE)
+
+
+cdef inline uint64_t _fnv1a_64(const char* data, size_t data_len) noexcept nogil:
+    cdef uint64_t h = 0xcbf29ce484222325ULL
+    cdef size_t i
+    for i in range(data_len):
+        h ^= <unsigned char>data[i]
+        h *= 0x100000001b3ULL
+    return h
+
+
+cdef inline uint64_t _mix64(uint64_t x) noexcept nogil:
+    """SplitMix64 finalizer for better bit dispersion."""
+    x ^= x >> 30
+    x *= 0xbf58476d1ce4e5b9ULL
+    x ^= x >> 27
+    x *= 0x94d049bb133111ebULL
+    x ^= x >> 31
+    return x
+
+
+cdef vector[uint64_t] _shingle_hashes(str text, size_t shingle_size):
+    """Hash overlapping word n-grams of the lower-cased input text."""
+    cdef list words = __word_regex.findall(text.lower())
+    cdef vector[uint64_t] hashes
+    cdef bytes shingle
+    cdef size_t i
+    if not words:
+        return hashes
+    if shingle_size < 1:
+        shingle_size = 1
+
+    cdef size_t num_shingles = max(1, <Py_ssize_t>len(words) - <Py_ssize_t>shingle_size + 1)
+    hashes.reserve(num_shingles)
+    for i in range(num_shingles):
+        shingle = ' '.join(words[i:i + shingle_size]).encode()
+        hashes.push_back(_mix64(_fnv1a_64(<const char*>shingle, len(shingle))))
+    return hashes
+
+
+def simhash64(str text, size_t shingle_size=3):
+    """
+    simhash64(text, shingle_size=3)
+
+    Calculate a 64-bit SimHash fingerprint of a plain text.
+
+    The text is lower-cased and tokenized into words, which are then combined into overlapping
+    shingles of ``shingle_size`` words. Similar texts have fingerprints with a small Hamming distance,
+    which can be calculated as ``bin(a ^ b).count('1')``. Fingerprints are deterministic and stable
+    across runs and platforms.
+
+    Texts with fewer words than ``shingle_size`` are hashed as a single shingle. The fingerprint
+    of an empty text is ``0``.
+
+    :param text: input text
+    :type text: str
+    :param shingle_size: number of words per shingle
+    :type shingle_size: int
+    :return: 64-bit fingerprint
+    :rtype: int
+    """
+    cdef vector[uint64_t] hashes = _shingle_hashes(text, shingle_size)
+    if hashes.empty():
+        return 0
+
+    cdef Py_ssize_t weights[64]
+    cdef size_t i, j
+    for j in range(64):
+        weights[j] = 0
+    for i in range(hashes.size()):
+        for j in range(64):
+            weights[j] += 1 if (hashes[i] >> j) & 1u else -1
+
+    cdef uint64_t fingerprint = 0
+    for j in range(64):
+        if weights[j] > 0:
+            fingerprint |= (<uint64_t>1) << j
+    return fingerprint
+
+
+def minhash(str text, size_t k=128, size_t shingle_size=3, uint64_t seed=0):
+    """
+    minhash(text, k=128, shingle_size=3, seed=0)
+
+    Calculate a MinHash signature of a plain text.
+
+    The text is tokenized into word shingles as described in :func:`simhash64` and hashed with
+    ``k`` independent hash functions derived from ``seed``. The fraction of equal positions in two
+    signatures computed with the same ``k``, ``shingle_size``, and ``seed`` is an estimate of the
+    Jaccard similarity of the two texts' shingle sets. Signatures are deterministic and stable
+    across runs and platforms.
+
+    The signature of an empty text consists of ``k`` times the maximum 64-bit integer.
+
+    :param text: input text
+    :type text: str
+    :param k: signature length (number of hash functions)
+    :type k: int
+    :param shingle_size: number of words per shingle
+    :type shingle_size: int
+    :param seed: seed for deriving the hash functions
+    :type seed: int
+    :return: list of ``k`` 64-bit minimum hash values
+    :rtype: t.List[int]
+    """
+    cdef vector[uint64_t] hashes = _shingle_hashes(text, shingle_size)
+    cdef vector[uint64_t] signature
+    signature.resize(k, <uint64_t>-1)
+
+    cdef uint64_t perm_seed
+    cdef uint64_t h
+    cdef size_t i, j
+    for j in range(k):
+        perm_seed = _mix64(seed + 0x9e3779b97f4a7c15ULL * (j + 1))
+        for i in range(hashes.size()):
+            h = _mix64(hashes[i] ^ perm_seed)
+            if h < signature[j]:
+                signature[j] = h
+
+    return list(signature)
@@ -280,6 +280,7 @@ cdef extern from "<lexbor/html/html.h>" nogil:
     lxb_dom_element_t * lxb_dom_document_element(lxb_dom_document_t *document)
 
     lxb_status_t lxb_html_serialize_tree_str(lxb_dom_node_t *node, lexbor_str_t *str)
+    lxb_status_t lxb_html_serialize_deep_str(lxb_dom_node_t *node, lexbor_str_t *str)
     const lxb_char_t* lxb_html_document_title(lxb_html_document_t *document, size_t *len)
     bint lxb_html_tag_is_category(lxb_tag_id_t tag_id, lxb_ns_id_t ns, lxb_html_tag_category_t cat)
 
@@ -446,6 +447,8 @@ cdef extern from "<lexbor/css/css.h>" nogil:
     lxb_selectors_t * lxb_selectors_destroy(lxb_selectors_t *selectors, bint self_destroy)
     lxb_status_t lxb_selectors_find(lxb_selectors_t *selectors, lxb_dom_node_t *root,
                                     lxb_css_selector_list_t *list, lxb_selectors_cb_f cb, void *ctx)
+    lxb_status_t lxb_selectors_match_node(lxb_selectors_t *selectors, lxb_dom_node_t *node,
+                                          lxb_css_selector_list_t *list, lxb_selectors_cb_f cb, void *ctx)
 
 
 cdef extern from "<lexbor/tag/tag.h>" nogil:
@@ -88,10 +88,16 @@ def get_ext_modules():
     resiliparse_extensions = [
         Extension('resiliparse.extract.html2text',
                   sources=[f'resiliparse/extract/html2text.pyx'], libraries=['lexbor', 're2'], **cpp_args),
+        Extension('resiliparse.extract.sanitize',
+                  sources=[f'resiliparse/extract/sanitize.pyx'], libraries=['lexbor'], **cpp_args),
+        Extension('resiliparse.extract.structured',
+                  sources=[f'resiliparse/extract/structured.pyx'], libraries=['lexbor'], **cpp_args),
         Extension('resiliparse.parse.encoding',
                   sources=[f'resiliparse/parse/encoding.pyx'], libraries=['uchardet', 'lexbor'], **cpp_args),
         Extension('resiliparse.parse.html',
                   sources=[f'resiliparse/parse/html.pyx'], libraries=['lexbor'], **cpp_args),
+        Extension('resiliparse.parse.text',
+                  sources=[f'resiliparse/parse/text.pyx'], **cpp_args),
     ]
     if os.name == 'posix':
         # Process Guards are unsupported on Windows
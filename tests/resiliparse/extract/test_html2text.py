@@ -133,6 +133,26 @@ Sorry, your browser doesn't support VB Script!
 Copyright (C) 2021 Foo Bar"""
 
 
+def test_space_normalization():
+    html = '<p>Caf&eacute; &amp; Bar&nbsp;&nbsp;&#x2019;s  Zero&#x200B;Width&#xFEFF;&#x2060;!</p>' \
+           '<p>Ideographic&#x3000;space&#x202F;and&#x2009;thin</p>'
+
+    assert extract_plain_text(html) == \
+           'Caf\u00e9 & Bar\u00a0\u00a0\u2019s Zero\u200bWidth\ufeff\u2060!\n\n' \
+           'Ideographic\u3000space\u202fand\u2009thin'
+    assert extract_plain_text(html, normalize_spaces=True) == \
+           'Caf\u00e9 & Bar \u2019s ZeroWidth!\n\nIdeographic space and thin'
+    assert extract_plain_text(html, preserve_formatting=False, normalize_spaces=True) == \
+           'Caf\u00e9 & Bar \u2019s ZeroWidth! Ideographic space and thin'
+
+    # Attribute texts are normalized as well
+    html = '<img alt="a&nbsp;b&#x200B;c"> <input value="x&nbsp;y"> <input placeholder="z&#x3000;w">'
+    assert extract_plain_text(html, alt_texts=True, form_fields=True) == \
+           'a\u00a0b\u200bc [ x\u00a0y ] [ z\u3000w ]'
+    assert extract_plain_text(html, alt_texts=True, form_fields=True, normalize_spaces=True) == \
+           'a bc [ x y ] [ z w ]'
+
+
 def test_main_content_extraction():
     assert extract_plain_text(tree, alt_texts=False, main_content=True) == \
            "foo\n\nbaz\nbar"
@@ -290,6 +310,18 @@ def test_empty_list_items():
            '  \u2022 A\n  \u2022 B'
 
 
+def test_deeply_nested_input():
+    # Traversal is iterative, so pathological nesting must not exhaust the stack
+    depth = 100000
+    tree = HTMLTree.parse('<div>' * depth + 'Hello <b>world</b>' + '</div>' * depth)
+    assert extract_plain_text(tree) == 'Hello world'
+    assert extract_plain_text(tree, main_content=True) == 'Hello world'
+    assert extract_plain_text(tree, preserve_formatting=False) == 'Hello world'
+
+    tree = HTMLTree.parse('<ul><li>' * 10000 + 'Item')
+    assert extract_plain_text(tree, list_bullets=False).strip() == 'Item'
+
+
 def test_real_word_data():
     # Cannot really compare expected outputs here, so only test that we have no crashes or anything
     i = 0
@@ -0,0 +1,48 @@
+import pytest
+
+from resiliparse.parse.html import HTMLTree
+from resiliparse.extract.sanitize import *
+
+
+def test_sanitize_default_policy():
+    html = '''<p onclick="x()" class="intro">Hello <b>world</b><script>alert(1)</script></p>\
+<div><custom>Keep <i>me</i></custom></div>\
+<a href="javascript:alert(1)">js</a><a href=" java\tscript:alert(1)">js2</a>\
+<a href="/relative" target="_blank">rel</a><a href="https://example.com" rel="nofollow">abs</a>\
+<img src="data:image/png;base64,AAAA" alt="pic"><!-- comment --><iframe src="https://example.com"></iframe>\
+<style>* { color: red; }</style>'''
+
+    assert sanitize_html(html) == '<p>Hello <b>world</b></p><div>Keep <i>me</i></div>' \
+                                  '<a>js</a><a>js2</a><a href="/relative">rel</a>' \
+                                  '<a href="https://example.com" rel="nofollow">abs</a><img alt="pic">'
+
+
+def test_sanitize_custom_policy():
+    policy = SanitizePolicy(allowed_tags=['p', 'a'], allowed_attrs={'a': ['href'], '*': ['class']})
+    assert sanitize_html('<p class="a" id="b">x <em>y</em></p>', policy) == '<p class="a">x y</p>'
+    assert sanitize_html('<p><a href="ftp://example.com" class="c">z</a></p>', policy) == '<p><a class="c">z</a></p>'
+
+    policy = SanitizePolicy(allowed_tags=['a'], allowed_attrs={'a': ['href']}, url_schemes=['ftp'])
+    assert sanitize_html('<a href="ftp://example.com">z</a>', policy) == '<a href="ftp://example.com">z</a>'
+
+    # Modifying a policy must not change the defaults
+    policy = SanitizePolicy()
+    policy.allowed_attrs['p'] = frozenset(['id'])
+    assert sanitize_html('<p id="x">y</p>', policy) == '<p id="x">y</p>'
+    assert sanitize_html('<p id="x">y</p>') == '<p>y</p>'
+
+    # Scripts and styles are dropped even if not listed
+    policy = SanitizePolicy(drop_tags=['span'])
+    assert sanitize_html('<p>a<span>b</span>c<script>d</script><style>e</style></p>', policy) == '<p>ac</p>'
+    assert SanitizePolicy(allowed_tags=['script'], drop_tags=[]).drop_tags == frozenset(['script', 'style'])
+
+
+def test_sanitize_tree_in_place():
+    tree = HTMLTree.parse('<p>Hello<script>alert(1)</script></p>')
+    assert tree.body.query_selector('script') is not None
+    assert sanitize_html(tree) == '<p>Hello</p>'
+    assert tree.body.query_selector('script') is None
+    assert sanitize_html(HTMLTree.parse('')) == ''
+
+    with pytest.raises(TypeError):
+        sanitize_html(b'<p>Hello</p>')
@@ -0,0 +1,392 @@
+import pytest
+
+from resiliparse.parse.html import HTMLTree, TEXT
+from resiliparse.extract.structured import *
+
+
+links_html = """<!doctype html>
+<html>
+<head>
+  <link rel="stylesheet" href="/style.css">
+  <script src="app.js"></script>
+</head>
+<body>
+  <a href="page.html" rel="nofollow">A   relative
+    link</a>
+  <a href="https://example.org/">Absolute</a>
+  <a href="#section">Fragment</a>
+  <a href=" javascript:void(0) ">JS</a>
+  <a href="mailto:info@example.com">Mail</a>
+  <a href="tel:+123456">Phone</a>
+  <a href="ftp://example.com/file">FTP</a>
+  <a name="anchor">No link</a>
+  <img src="data:image/png;base64,AAAA" alt="">
+  <iframe src="//cdn.example.com/embed"></iframe>
+</body>
+</html>"""
+
+
+def test_extract_links():
+    links = extract_links(links_html, base_url='https://example.com/dir/index.html')
+    assert [(l.tag, l.attr) for l in links] == [
+        ('link', 'href'), ('script', 'src'), ('a', 'href'), ('a', 'href'), ('a', 'href'), ('a', 'href'),
+        ('a', 'href'), ('a', 'href'), ('a', 'href'), ('img', 'src'), ('iframe', 'src')]
+    assert [l.url for l in links] == [
+        'https://example.com/style.css',
+        'https://example.com/dir/app.js',
+        'https://example.com/dir/page.html',
+        'https://example.org/',
+        'https://example.com/dir/index.html#section',
+        'javascript:void(0)',
+        'mailto:info@example.com',
+        'tel:+123456',
+        'ftp://example.com/file',
+        'data:image/png;base64,AAAA',
+        'https://cdn.example.com/embed']
+    assert [l.link_type for l in links] == [
+        URL, URL, URL, URL, FRAGMENT, JAVASCRIPT, MAILTO, TEL, OTHER, DATA, URL]
+
+    assert links[2].text == 'A relative link'
+    assert links[2].rel == 'nofollow'
+    assert links[2].raw_url == 'page.html'
+    assert links[0].text == ''
+    assert links[0].rel == 'stylesheet'
+    assert links[5].raw_url == ' javascript:void(0) '
+
+
+def test_extract_links_base_href():
+    html = '<head><base href="/docs/"></head><body><a href="intro.html">Intro</a><a href="#top">Top</a></body>'
+    assert [l.url for l in extract_links(html, base_url='https://example.com/index.html')] == \
+           ['https://example.com/docs/intro.html', 'https://example.com/docs/#top']
+
+    # Relative links are left unchanged without absolute base URL
+    assert [l.url for l in extract_links('<a href="intro.html">Intro</a>')] == ['intro.html']
+    assert [l.url for l in extract_links(html)] == ['/docs/intro.html', '/docs/#top']
+
+    tree = HTMLTree.parse(html)
+    assert extract_links(tree, 'https://example.com/') == extract_links(html, 'https://example.com/')
+    assert extract_links('') == []
+
+    with pytest.raises(TypeError):
+        extract_links(b'<a href="x">x</a>')
+
+
+def test_extract_head_directives():
+    html = """<head>
+      <meta name="Robots" content="NoIndex, nofollow,  max-snippet:50">
+      <meta name="googlebot" content="noarchive">
+      <link rel="alternate" href="/feed">
+      <link rel="Canonical" href="/article">
+      <link rel="canonical" href="/other">
+      <meta property="og:title" content="Title">
+      <meta property="og:title" content="Other title">
+      <meta property="og:image" content="https://example.com/img.png">
+      <meta name="twitter:card" content="summary">
+      <meta name="description" content="Description">
+    </head>"""
+
+    directives = extract_head_directives(html, base_url='https://example.com/a/b?x=1')
+    assert directives.robots == {'noindex', 'nofollow', 'max-snippet:50'}
+    assert directives.noindex
+    assert directives.nofollow
+    assert directives.canonical == 'https://example.com/article'
+    assert directives.opengraph == {'og:title': 'Title', 'og:image': 'https://example.com/img.png'}
+    assert directives.twitter == {'twitter:card': 'summary'}
+
+    directives = extract_head_directives(html, user_agent='Googlebot')
+    assert directives.robots == {'noindex', 'nofollow', 'max-snippet:50', 'noarchive'}
+    assert directives.canonical == '/article'
+
+    directives = extract_head_directives('<meta name="robots" content="none"><title>x</title>')
+    assert directives.noindex and directives.nofollow
+    assert directives.canonical is None
+
+    directives = extract_head_directives('<p>Hello</p>')
+    assert directives.robots == set()
+    assert not directives.noindex and not directives.nofollow
+    assert directives.canonical is None
+    assert directives.opengraph == {}
+    assert directives.twitter == {}
+
+
+def test_extract_tables():
+    html = """<table>
+      <caption>Prices</caption>
+      <thead><tr><th>Name</th><th colspan="2">Price</th></tr></thead>
+      <tbody>
+        <tr><td rowspan="2">Apple</td><td>1</td><td>EUR</td></tr>
+        <tr><td>2</td><td>USD</td></tr>
+        <tr><td>Pear <b>green</b></td><td colspan="2">n/a<table><tr><th>Nested</th></tr><tr><td>x</td></tr></table></td></tr>
+      </tbody>
+    </table>
+    <table><tr><td>a</td><td>b</td></tr><tr><td></td><td></td></tr><tr><td>c</td></tr></table>"""
+
+    tables = extract_tables(html)
+    assert len(tables) == 3
+
+    assert tables[0].caption == 'Prices'
+    assert tables[0].headers == ['Name', 'Price', 'Price']
+    assert tables[0].rows == [['Apple', '1', 'EUR'], ['Apple', '2', 'USD'], ['Pear green', 'n/a', 'n/a']]
+    assert tables[0].records()[:2] == [{'Name': 'Apple', 'Price': '1', 2: 'EUR'},
+                                       {'Name': 'Apple', 'Price': '2', 2: 'USD'}]
+
+    assert tables[1].caption == ''
+    assert tables[1].headers == ['Nested']
+    assert tables[1].rows == [['x']]
+
+    assert tables[2].headers == []
+    assert tables[2].rows == [['a', 'b'], ['c', '']]
+    assert tables[2].records() == [{0: 'a', 1: 'b'}, {0: 'c', 1: ''}]
+
+    assert extract_tables('<p>No tables</p>') == []
+    assert extract_tables('<table></table>')[0].rows == []
+
+
+def test_extract_tables_spans():
+    html = """<table>
+      <tr><th>Key</th><th>Value</th><th></th></tr>
+      <tr><td rowspan="3">A</td><td colspan="2">1</td></tr>
+      <tr><td>2</td><td rowspan="0">3</td></tr>
+      <tr><td colspan="foo">4</td></tr>
+    </table>"""
+
+    table = extract_tables(HTMLTree.parse(html))[0]
+    assert table.headers == ['Key', 'Value', '']
+    assert table.rows == [['A', '1', '1'], ['A', '2', '3'], ['A', '4', '3']]
+    assert table.records() == [{'Key': 'A', 'Value': '1', 2: '1'},
+                               {'Key': 'A', 'Value': '2', 2: '3'},
+                               {'Key': 'A', 'Value': '4', 2: '3'}]
+
+    # Cells do not span beyond their row group
+    html = """<table>
+      <tbody><tr><td rowspan="0">A</td><td rowspan="5">B</td></tr><tr></tr></tbody>
+      <tbody><tr><td>C</td><td>D</td></tr></tbody>
+    </table>"""
+    assert extract_tables(html)[0].rows == [['A', 'B'], ['A', 'B'], ['C', 'D']]
+
+
+def test_extract_tables_max_cells():
+    # A single spanning cell must not expand into a huge grid
+    html = '<table><tr><td colspan="1000" rowspan="65534">x</td>' + '<tr>' * 65533 + '</table><table></table>'
+    tables = extract_tables(html)
+    assert len(tables) == 1
+    assert len(tables[0].rows) == 1000
+    assert tables[0].rows[-1] == ['x'] * 1000
+
+    html = """<table><tr><td>a</td><td>b</td></tr><tr><td>c</td><td>d</td></tr><tr><td>e</td></tr></table>
+    <table><tr><td>f</td></tr></table>"""
+    assert [t.rows for t in extract_tables(html, max_cells=4)] == [[['a', 'b'], ['c', 'd']]]
+    assert [t.rows for t in extract_tables(html, max_cells=7)] == [[['a', 'b'], ['c', 'd'], ['e', '']], [['f']]]
+    assert [t.rows for t in extract_tables(html, max_cells=0)] == [[['a', 'b'], ['c', 'd'], ['e', '']], [['f']]]
+
+
+def test_iter_text_nodes():
+    html = """<html><head><title>Title</title><style>p { color: red; }</style></head>
+    <body>
+      <p>Hello <b>world</b>!</p>
+      <div hidden><span>Hidden <i>text</i></span></div>
+      <div aria-hidden="true">ARIA hidden</div>
+      <div style="Display: None">Display none</div>
+      <p>Visible</p>
+      <noscript>No script</noscript>
+      <script>let x = 1;</script>
+    </body></html>"""
+
+    nodes = list(iter_text_nodes(html))
+    assert [(n.text, n.tag, n.is_block, n.hidden, n.in_script) for n in nodes] == [
+        ('Title', 'title', False, False, False),
+        ('p { color: red; }', 'style', False, False, True),
+        ('Hello ', 'p', True, False, False),
+        ('world', 'b', False, False, False),
+        ('!', 'p', True, False, False),
+        ('Hidden ', 'span', False, True, False),
+        ('text', 'i', False, True, False),
+        ('ARIA hidden', 'div', True, True, False),
+        ('Display none', 'div', True, True, False),
+        ('Visible', 'p', True, False, False),
+        ('No script', 'noscript', False, False, True),
+        ('let x = 1;', 'script', False, False, True),
+    ]
+    assert nodes[3].node.type == TEXT
+    assert nodes[3].parent.tag == 'b'
+
+    tree = HTMLTree.parse('<p>a</p> <p>b</p>')
+    assert [n.text for n in iter_text_nodes(tree)] == ['a', 'b']
+    assert [n.text for n in iter_text_nodes(tree, skip_whitespace=False)] == ['a', ' ', 'b']
+    assert list(iter_text_nodes('')) == []
+
+
+def test_extract_structured_data():
+    html = """<html><head>
+      <script type="application/ld+json">{"@context": "https://schema.org", "@type": "Article", "headline": "Title"}</script>
+      <script type="application/ld+json">{"@type": "Broken",}</script>
+      <script type="text/javascript">var x = {"a": 1};</script>
+    </head>
+    <body>
+      <div itemscope itemtype="https://schema.org/Movie" itemid="/movies/1" itemref="extra">
+        <h1 itemprop="name">Avatar</h1>
+        <span itemprop="director" itemscope itemtype="https://schema.org/Person">
+          Director: <span itemprop="name">James   Cameron</span>
+        </span>
+        <a href="trailer.html" itemprop="trailer url">Trailer</a>
+        <img src="/poster.jpg" itemprop="image">
+        <meta itemprop="duration" content="PT2H42M">
+        <time itemprop="datePublished" datetime="2009-12-18">December 18, 2009</time>
+        <data itemprop="rating" value="7.8">Good</data>
+      </div>
+      <p id="extra"><span itemprop="genre">Science fiction</span></p>
+      <script type=" Application/LD+JSON ">[{"@type": "Person"}]</script>
+      <div itemscope><span itemprop="note">Untyped</span></div>
+    </body></html>"""
+
+    data = extract_structured_data(html, base_url='https://example.com/movies/')
+    assert [d.syntax for d in data] == ['json-ld', 'json-ld', 'microdata', 'json-ld', 'microdata']
+
+    assert data[0].valid
+    assert data[0].data == {'@context': 'https://schema.org', '@type': 'Article', 'headline': 'Title'}
+    assert data[0].raw == '{"@context": "https://schema.org", "@type": "Article", "headline": "Title"}'
+
+    assert not data[1].valid
+    assert data[1].data is None
+    assert data[1].error
+    assert data[1].raw == '{"@type": "Broken",}'
+
+    assert data[2].valid
+    assert data[2].raw is None
+    assert data[2].data == {
+        'type': ['https://schema.org/Movie'],
+        'id': 'https://example.com/movies/1',
+        'properties': {
+            'name': ['Avatar'],
+            'director': [{'type': ['https://schema.org/Person'], 'properties': {'name': ['James Cameron']}}],
+            'trailer': ['https://example.com/movies/trailer.html'],
+            'url': ['https://example.com/movies/trailer.html'],
+            'image': ['https://example.com/poster.jpg'],
+            'duration': ['PT2H42M'],
+            'datePublished': ['2009-12-18'],
+            'rating': ['7.8'],
+            'genre': ['Science fiction'],
+        }
+    }
+
+    assert data[3].data == [{'@type': 'Person'}]
+    assert data[4].data == {'properties': {'note': ['Untyped']}}
+
+    assert extract_structured_data('<p>Nothing here</p>') == []
+
+
+def test_extract_structured_data_edge_cases():
+    html = """<script type="application/ld+json"><!-- {"a": 1} --></script>
+    <script type="application/ld+json"></script>
+    <div id="a" itemscope itemref="a b"><span itemprop="x">1</span></div>
+    <div id="b"><div itemprop="self" itemscope itemref="b"><span itemprop="y">2</span></div></div>"""
+
+    data = extract_structured_data(html)
+    assert data[0].data == {'a': 1}
+    assert not data[1].valid
+    assert data[1].raw == ''
+    assert data[2].data['properties']['x'] == ['1']
+    assert data[2].data['properties']['self'] == [{'properties': {'y': ['2'], 'self': [None]}}]
+
+
+def test_deeply_nested_input():
+    # Nested microdata items are walked iteratively, so pathological nesting must not exhaust the stack
+    depth = 100000
+    data = extract_structured_data('<div itemscope>' + '<div itemprop="a" itemscope>' * depth +
+                                   '<span itemprop="b">Hello</span>')
+    assert len(data) == 1
+    item = data[0].data
+    for _ in range(depth):
+        item = item['properties']['a'][0]
+    assert item == {'properties': {'b': ['Hello']}}
+
+    json_ld = '[' * depth
+    data = extract_structured_data(f'<script type="application/ld+json">{json_ld}</script><p itemscope></p>')
+    assert len(data) == 2
+    assert not data[0].valid
+    assert data[0].raw == json_ld
+    assert data[1].data == {'properties': {}}
+
+
+def test_parse_data_uri():
+    uri = parse_data_uri('data:image/png;base64,iVBORw0KGgo=')
+    assert uri.mime_type == 'image/png'
+    assert uri.is_base64
+    assert uri.data == b'\x89PNG\r\n\x1a\n'
+
+    # White space and missing padding are tolerated
+    assert parse_data_uri(' DATA:image/png;BASE64,iVBO Rw0K\nGgo ').data == b'\x89PNG\r\n\x1a\n'
+    assert parse_data_uri('data:;base64,SGVsbG8').data == b'Hello'
+
+    uri = parse_data_uri('data:text/html;charset=utf-8,%3Cp%3EHello%20W%C3%B6rld%3C%2Fp%3E')
+    assert uri.mime_type == 'text/html;charset=utf-8'
+    assert not uri.is_base64
+    assert uri.data == '<p>Hello Wörld</p>'.encode()
+
+    assert parse_data_uri('data:,Hello').mime_type == 'text/plain;charset=US-ASCII'
+    assert parse_data_uri('data:;charset=utf-8,Hello').mime_type == 'text/plain;charset=utf-8'
+    assert parse_data_uri('data:,').data == b''
+
+    assert parse_data_uri('https://example.com/') is None
+    assert parse_data_uri('data:image/png;base64') is None
+    assert parse_data_uri('data:image/png;base64,A') is None
+    assert parse_data_uri('data:image/png;base64,!!!!') is None
+
+    links = extract_links('<img src="data:image/gif;base64,R0lGODlhAQABAAAAACw=">')
+    assert links[0].link_type == DATA
+    assert parse_data_uri(links[0].url).data == b'GIF89a\x01\x00\x01\x00\x00\x00\x00,'
+
+
+def test_extract_forms():
+    html = """<html><head><base href="/app/"></head><body>
+    <form action="login" method="POST">
+        <input name="user">
+        <input type="Password" name="pass">
+        <input type="hidden" name="token" value="abc">
+        <textarea name="note">Hello</textarea>
+        <select name="lang">
+            <option value="en">English</option>
+            <option selected>  Deutsch </option>
+        </select>
+        <button>Log in</button>
+    </form>
+    <form role="search">
+        <input type="search" name="q">
+        <select name="scope"><option value="all">All</option><option value="web">Web</option></select>
+    </form>
+    <form id="f" action="javascript:void(0)" method="put"></form>
+    <input name="outside" form="f">
+    <input name="orphan">
+    </body></html>"""
+
+    forms = extract_forms(html, base_url='https://example.com/index.html')
+    assert len(forms) == 3
+
+    assert forms[0].action == 'https://example.com/app/login'
+    assert forms[0].raw_action == 'login'
+    assert forms[0].method == 'post'
+    assert forms[0].fields == [
+        FormField('user', 'text'),
+        FormField('pass', 'password'),
+        FormField('token', 'hidden', 'abc'),
+        FormField('note', 'textarea', 'Hello'),
+        FormField('lang', 'select', 'Deutsch'),
+        FormField('', 'submit'),
+    ]
+
+    # Missing action resolves to the document URL
+    assert forms[1].action == 'https://example.com/index.html'
+    assert forms[1].raw_action == ''
+    assert forms[1].method == 'get'
+    assert forms[1].fields == [FormField('q', 'search'), FormField('scope', 'select', 'all')]
+
+    # Non-HTTP actions are not resolved, invalid methods default to GET
+    assert forms[2].action == 'javascript:void(0)'
+    assert forms[2].method == 'get'
+    assert forms[2].fields == [FormField('outside', 'text')]
+
+    assert extract_forms(HTMLTree.parse('<form action="/s"><input name="q"></form>'))[0].action == '/s'
+    assert extract_forms('<input name="q">') == []
+
+    with pytest.raises(TypeError):
+        extract_forms(None)
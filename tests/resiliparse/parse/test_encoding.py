@@ -47,6 +47,35 @@ def test_bytes_to_str():
     assert bytes_to_str(b'+Condensed', 'utf-7', fallback_encodings=[]) == 'ઉ笞'
 
 
+def test_strip_bom():
+    assert strip_bom(b'\xef\xbb\xbfHello') == (b'Hello', 'utf-8')
+    assert strip_bom(b'\xff\xfeH\x00i\x00') == (b'H\x00i\x00', 'utf-16-le')
+    assert strip_bom(b'\xfe\xff\x00H\x00i') == (b'\x00H\x00i', 'utf-16-be')
+    assert strip_bom(b'\xff\xfe\x00\x00H\x00\x00\x00') == (b'H\x00\x00\x00', 'utf-32-le')
+    assert strip_bom(b'\x00\x00\xfe\xff\x00\x00\x00H') == (b'\x00\x00\x00H', 'utf-32-be')
+    assert strip_bom(b'Hello') == (b'Hello', None)
+    assert strip_bom(b'') == (b'', None)
+    assert strip_bom(b'\xef\xbb') == (b'\xef\xbb', None)
+
+    # BOM overrides meta charset and auto-detection
+    html = b"""\xef\xbb\xbf<!doctype html><meta charset="iso-8859-1"><title>Foo</title>"""
+    assert detect_encoding(html, from_html_meta=True) == 'utf-8'
+    assert detect_encoding(html, from_html_meta=False) == 'utf-8'
+    assert detect_encoding(b'\xfe\xff\x00H\x00e\x00l\x00l\x00o') == 'utf-16-be'
+
+    # Without WHATWG remapping, BOMs are left to uchardet
+    assert detect_encoding(b'\xff\xfeH\x00e\x00l\x00l\x00o\x00', html5_compatible=False) == 'utf-16'
+
+    # UTF-32-LE BOMs are not mistaken for UTF-16-LE BOMs
+    utf32 = b'\xff\xfe\x00\x00' + 'Hello World'.encode('utf-32-le')
+    assert detect_encoding(utf32, html5_compatible=False) == 'utf-32'
+    # UTF-32 is not a WHATWG encoding and falls back to UTF-8
+    assert detect_encoding(utf32, html5_compatible=True) == 'utf-8'
+
+    body, enc = strip_bom(html)
+    assert bytes_to_str(body, enc).startswith('<!doctype html>')
+
+
 def test_mime_detect():
     mime_bytes = [
         (b'%PDF-1.5\n%\xd0\xd4\xc5\xd8', 'application/pdf'),
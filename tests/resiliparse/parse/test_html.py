@@ -180,6 +180,30 @@ def test_selection():
         tree.body.query_selector('..abc')
 
 
+def test_closest():
+    tree = HTMLTree.parse(html)
+
+    span = tree.body.query_selector('#b span')
+    assert span.closest('p') is tree.body.get_element_by_id('b')
+    assert span.closest('main') is tree.body.get_element_by_id('foo')
+    assert span.closest('.bar') is span
+    assert span.closest('body') is tree.body
+    assert span.closest('#a') is None
+    assert span.closest('table') is None
+    assert span.first_child.closest('span') is span
+
+    tree = HTMLTree.parse('<table><tr><th>Key 1</th><td>Value 1</td></tr>'
+                          '<tr><th class="k">Key 2</th><td>Value 2</td></tr></table>')
+    th = tree.body.query_selector('th.k')
+    assert th.next_element.tag == 'td'
+    assert th.next_element.text == 'Value 2'
+    assert th.closest('tr').prev_element.query_selector('td').text == 'Value 1'
+    assert th.closest('table').closest('body') is tree.body
+
+    with pytest.raises(ValueError):
+        th.closest('..abc')
+
+
 def test_collection():
     tree = HTMLTree.parse(html)
 
@@ -319,6 +343,27 @@ def test_serialization():
     assert text.type == TEXT
     assert repr(text) == str(text) == text.text
 
+    assert tree.body.get_element_by_id('a').inner_html == 'Hello <span class="bar">world</span>!'
+    assert tree.create_element('div').inner_html == ''
+
+    # Escaping and void elements
+    element = tree.create_element('div')
+    element.setattr('title', 'a "quoted" & <b>')
+    element.append_child(tree.create_text_node('x < y & z'))
+    element.append_child(tree.create_element('br'))
+    element.append_child(tree.create_element('img'))
+    assert element.html == '<div title="a &quot;quoted&quot; &amp; <b>">x &lt; y &amp; z<br><img></div>'
+    assert element.inner_html == 'x &lt; y &amp; z<br><img>'
+
+    element.inner_html = '<p>New</p>'
+    assert element.html == '<div><p>New</p></div>'
+
+    # Serialize modified document
+    tree.body.get_element_by_id('a').decompose()
+    assert tree.body.get_element_by_id('a') is None
+    assert 'id="a"' not in str(tree)
+    assert '<main id="foo">' in str(tree)
+
 
 def test_traversal():
     tree = HTMLTree.parse(html)
@@ -478,10 +523,34 @@ def test_dom_manipulation():
     assert main_element.remove_child(main_element.last_child) is new_element2
     assert main_element.remove_child(main_element.last_child) is new_element
 
+    main_element.append_child(new_element)
+    assert new_element.remove() is new_element
+    assert new_element.parent is None
+    assert new_element.remove() is new_element
+    assert len(tree.body.query_selector_all('main > *')) == 2
+
     new_element.decompose()
     assert repr(new_element) == '<INVALID ELEMENT>'
 
 
+def test_rewrite_links():
+    tree = HTMLTree.parse('<p><a href="/foo">Foo</a> <a href="https://example.com/bar" target="_blank">Bar</a>'
+                          '<a>No link</a><a href="/baz" onclick="track()">Baz</a></p>')
+
+    for a in tree.body.query_selector_all('a[href]'):
+        a['href'] = 'https://replay.example.org/?url=' + a['href']
+        a.delattr('target')
+        if a.hasattr('onclick'):
+            a.delattr('onclick')
+            a.append_child(tree.create_text_node(' (tracked)'))
+
+    tree.body.query_selector('a:not([href])').remove()
+
+    assert tree.body.inner_html == '<p><a href="https://replay.example.org/?url=/foo">Foo</a> ' \
+                                   '<a href="https://replay.example.org/?url=https://example.com/bar">Bar</a>' \
+                                   '<a href="https://replay.example.org/?url=/baz">Baz (tracked)</a></p>'
+
+
 def test_node_value():
     tree = HTMLTree.parse(html)
 
@@ -512,6 +581,24 @@ def test_inner_html_and_text():
     assert text.text == 'abc'
 
 
+def test_deeply_nested_input():
+    depth = 100000
+    tree = HTMLTree.parse('<div>' * depth + '<p class="x">Hello world</p>' + '</div>' * depth)
+    assert len(tree.body.get_elements_by_tag_name('div')) == depth
+    assert len(tree.body.query_selector_all('div')) == depth
+    assert tree.body.query_selector('.x').text == 'Hello world'
+    assert tree.body.query_selector('.x').closest('body') is tree.body
+    assert tree.body.text == 'Hello world'
+
+    def start_cb(ctx):
+        ctx.max_depth = max(ctx.max_depth, ctx.depth)
+
+    ctx = DOMContext()
+    ctx.max_depth = 0
+    traverse_dom(tree.body, start_cb, context=ctx, elements_only=True)
+    assert ctx.max_depth == depth + 1
+
+
 def test_real_world_data():
     count = 0
     for rec in ArchiveIterator(FileStream(os.path.join(DATA_DIR, 'warcfile.warc')),
@@ -0,0 +1,81 @@
+from resiliparse.parse.text import *
+
+
+def test_split_sentences():
+    assert split_sentences('') == []
+    assert split_sentences('   \n ') == []
+    assert split_sentences('No terminator') == ['No terminator']
+    assert split_sentences('Hello world. How are you? Fine!') == ['Hello world.', 'How are you?', 'Fine!']
+
+    # Decimals, abbreviations, and initials
+    assert split_sentences('It costs $3.50 per item. Visit example.com today.') == \
+        ['It costs $3.50 per item.', 'Visit example.com today.']
+    assert split_sentences('Dr. Smith met Mr. Jones, e.g. at the U.S. embassy. Then he left.') == \
+        ['Dr. Smith met Mr. Jones, e.g. at the U.S. embassy.', 'Then he left.']
+    assert split_sentences('J. R. R. Tolkien wrote it. Done.') == ['J. R. R. Tolkien wrote it.', 'Done.']
+    assert split_sentences('The answer is no. We left.') == ['The answer is no.', 'We left.']
+    assert split_sentences('See No. 5 and nos. 3 and 4. Done.') == ['See No. 5 and nos. 3 and 4.', 'Done.']
+    assert split_sentences('Neither did I. Then we left.') == ['Neither did I.', 'Then we left.']
+
+    # Ellipses, repeated terminators, and closing quotes
+    assert split_sentences('Wait... what? Really?! "Yes." He left.') == \
+        ['Wait... what?', 'Really?!', '"Yes."', 'He left.']
+    assert split_sentences('Wait... Then it happened.') == ['Wait...', 'Then it happened.']
+
+    # Paragraph breaks and single newlines
+    assert split_sentences('Headline\n\nFirst line\ncontinues here. Next.') == \
+        ['Headline', 'First line\ncontinues here.', 'Next.']
+
+    # CJK
+    assert split_sentences('東京に行きました。次の日に帰りました。') == ['東京に行きました。', '次の日に帰りました。']
+
+
+def test_split_sentences_lang():
+    assert split_sentences('Das ist z.B. gut. Vgl. Abs. 3 hier. Ende.', lang='de') == \
+        ['Das ist z.B. gut.', 'Vgl. Abs. 3 hier.', 'Ende.']
+    assert split_sentences('Siehe Abs. 3 hier.', lang='en') == ['Siehe Abs.', '3 hier.']
+    assert split_sentences('Mme. Dupont est là. Oui.', lang='fr') == ['Mme. Dupont est là.', 'Oui.']
+    assert split_sentences('Dijo que no. Luego se fue.', lang='es') == ['Dijo que no.', 'Luego se fue.']
+
+
+def test_sentence_spans():
+    text = '  First sentence.  Second one!  '
+    spans = sentence_spans(text)
+    assert spans == [(2, 17), (19, 30)]
+    assert [text[s:e] for s, e in spans] == split_sentences(text)
+
+
+def test_simhash64():
+    a = 'The quick brown fox jumps over the lazy dog. It was a sunny day in the park and everybody was happy.'
+    b = 'The quick brown fox jumps over the lazy dog! It was a sunny day in the park and everyone was happy.'
+    c = 'Completely unrelated text about stock markets, bonds, interest rates and the economy of small nations.'
+    dist = lambda x, y: bin(x ^ y).count('1')
+
+    # Fingerprints must be stable across runs
+    assert simhash64('Hello world') == 0x5cb585112be1151
+    assert simhash64('') == 0
+    assert simhash64('...') == 0
+    assert 0 <= simhash64(a) < 2 ** 64
+
+    assert simhash64(a) == simhash64(a.upper())
+    assert dist(simhash64(a), simhash64(b)) < 16
+    assert dist(simhash64(a), simhash64(c)) > dist(simhash64(a), simhash64(b))
+    assert simhash64(a, shingle_size=1) != simhash64(a)
+
+
+def test_minhash():
+    a = 'The quick brown fox jumps over the lazy dog. It was a sunny day in the park and everybody was happy.'
+    b = 'The quick brown fox jumps over the lazy dog! It was a sunny day in the park and everyone was happy.'
+    c = 'Completely unrelated text about stock markets, bonds, interest rates and the economy of small nations.'
+    sim = lambda x, y: sum(p == q for p, q in zip(x, y)) / len(x)
+
+    assert minhash('Hello world', k=2) == [7282452771320789254, 17531463298590410660]
+    assert len(minhash(a)) == 128
+    assert len(minhash(a, k=16)) == 16
+    assert minhash('', k=4) == [2 ** 64 - 1] * 4
+
+    assert minhash(a) == minhash(a)
+    assert minhash(a, seed=1) != minhash(a)
+    assert sim(minhash(a), minhash(a.lower())) == 1.0
+    assert 0.5 < sim(minhash(a), minhash(b)) < 1.0
+    assert sim(minhash(a), minhash(c)) < 0.1